@@ -7,6 +7,127 @@ fn benchmark_placeholder(c: &mut Criterion) {
         })
     });
 }
-criterion_group!(benches, benchmark_placeholder,);
+
+#[cfg(all(feature = "helpers", feature = "default-crypto"))]
+fn sample_payload_config() -> redstone::core::config::Config {
+    use redstone::{core::config::Config, helpers::hex::make_signer_address, FeedId};
+
+    Config::try_new(
+        3,
+        [
+            "83cba8c619fb629b81a65c2e67fe15cf3e3c9747",
+            "2c59617248994d12816ee1fa77ce0a64eeb456bf",
+            "1ea62d73edf8ac05dfcea1a34b9796e937a29eff",
+            "12470f7aba85c8b81d63137dd5925d6ee114952b",
+            "109b4a318a4f5ddcbca6349b45f881b4137deafb",
+        ]
+        .map(make_signer_address)
+        .to_vec(),
+        vec![
+            FeedId::from_ticker("ETH").unwrap(),
+            FeedId::from_ticker("BTC").unwrap(),
+        ],
+        1707307760000.into(),
+        None,
+        None,
+    )
+    .unwrap()
+}
+
+#[cfg(all(feature = "helpers", feature = "default-crypto"))]
+fn payload_processing(c: &mut Criterion) {
+    use redstone::{
+        core::process_payload, default_ext::DefaultCrypto, helpers::hex::sample_payload_bytes,
+        network::Dummy, RedStoneConfigImpl,
+    };
+
+    let config: RedStoneConfigImpl<DefaultCrypto, Dummy> = sample_payload_config().into();
+    let payload = sample_payload_bytes();
+
+    c.bench_function("payload_processing", |b| {
+        b.iter(|| process_payload(&config, payload.clone()).unwrap())
+    });
+}
+
+/// Compares the aggregation strategies over the 15 data packages of the sample payload.
+///
+/// The payload is decoded once, so only the validation and the aggregation are measured.
+#[cfg(all(feature = "helpers", feature = "default-crypto"))]
+fn aggregation_strategies(c: &mut Criterion) {
+    use criterion::{BatchSize, Throughput};
+    use redstone::{
+        core::{config::Aggregation, decode_payload, process_decoded_payload},
+        default_ext::DefaultCrypto,
+        helpers::hex::sample_payload_bytes,
+        network::Dummy,
+        RedStoneConfigImpl,
+    };
+
+    let mut group = c.benchmark_group("aggregation_strategies");
+
+    for aggregation in [
+        Aggregation::Median,
+        Aggregation::Mean,
+        Aggregation::Min,
+        Aggregation::Max,
+    ] {
+        let config: RedStoneConfigImpl<DefaultCrypto, Dummy> =
+            sample_payload_config().with_aggregation(aggregation).into();
+        let payload = decode_payload(&config, sample_payload_bytes()).unwrap();
+
+        group.throughput(Throughput::Elements(payload.data_packages().len() as u64));
+        group.bench_function(format!("{aggregation:?}"), |b| {
+            b.iter_batched(
+                || payload.clone(),
+                |payload| process_decoded_payload(&config, payload).unwrap(),
+                BatchSize::SmallInput,
+            )
+        });
+    }
+
+    group.finish();
+}
+
+/// Compares the sequential and the parallel signer recovery of the 15 data packages of the sample payload.
+#[cfg(all(feature = "helpers", feature = "default-crypto", feature = "rayon"))]
+fn payload_decoding(c: &mut Criterion) {
+    use redstone::{
+        core::{decode_payload, decode_payload_sequential},
+        default_ext::DefaultCrypto,
+        helpers::hex::sample_payload_bytes,
+        network::Dummy,
+        RedStoneConfigImpl,
+    };
+
+    let config: RedStoneConfigImpl<DefaultCrypto, Dummy> = sample_payload_config().into();
+    let payload = sample_payload_bytes();
+    let mut group = c.benchmark_group("payload_decoding");
+
+    group.bench_function("sequential", |b| {
+        b.iter(|| decode_payload_sequential(&config, payload.clone()).unwrap())
+    });
+    group.bench_function("parallel", |b| {
+        b.iter(|| decode_payload(&config, payload.clone()).unwrap())
+    });
+
+    group.finish();
+}
+
+#[cfg(not(all(feature = "helpers", feature = "default-crypto")))]
+fn payload_processing(_c: &mut Criterion) {}
+
+#[cfg(not(all(feature = "helpers", feature = "default-crypto")))]
+fn aggregation_strategies(_c: &mut Criterion) {}
+
+#[cfg(not(all(feature = "helpers", feature = "default-crypto", feature = "rayon")))]
+fn payload_decoding(_c: &mut Criterion) {}
+
+criterion_group!(
+    benches,
+    benchmark_placeholder,
+    payload_processing,
+    aggregation_strategies,
+    payload_decoding,
+);
 
 criterion_main!(benches);
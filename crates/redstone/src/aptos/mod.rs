@@ -0,0 +1,67 @@
+//! Aptos extension
+//!
+//! Implementation of the config suited for the Aptos network, with the crypto operations matching
+//! the `aptos_std::secp256k1::ecdsa_recover` and `aptos_std::aptos_hash::keccak256` natives,
+//! usable for the off-chain verifiers and the Move native bindings.
+//!
+//! The Aptos natives use the `libsecp256k1` library and accept the raw recovery ids (`0..=3`),
+//! not the `27`/`28` ethereum `v` values. The `v` byte of the signature is normalized in the
+//! [`Crypto::recover_address`] function, and only the `0` and `1` recovery ids are accepted,
+//! as the ethereum signatures don't use the other ones.
+
+use libsecp256k1::{recover, Error as Secp256k1Error, Message, RecoveryId, Signature};
+use sha3::{Digest, Keccak256};
+
+use crate::{
+    crypto::{check_recovery_byte, Crypto, CryptoError},
+    network::StdEnv,
+    Bytes, RedStoneConfigImpl,
+};
+
+/// Implementation of `RedstoneConfig` specialized for operations on the Aptos.
+pub type AptosRedStoneConfig = RedStoneConfigImpl<AptosCrypto, AptosEnv>;
+
+pub type AptosEnv = StdEnv;
+pub enum AptosCrypto {}
+
+impl Crypto for AptosCrypto {
+    type KeccakOutput = [u8; 32];
+
+    fn keccak256(input: impl AsRef<[u8]>) -> Self::KeccakOutput {
+        Keccak256::new_with_prefix(input).finalize().into()
+    }
+
+    fn recover_public_key(
+        recovery_byte: u8,
+        signature_bytes: impl AsRef<[u8]>,
+        message_hash: Self::KeccakOutput,
+    ) -> Result<Bytes, CryptoError> {
+        let recovery_id = RecoveryId::parse(check_recovery_byte(recovery_byte)?)
+            .map_err(|_| CryptoError::RecoveryByte(recovery_byte))?;
+        let signature = Signature::parse_standard_slice(signature_bytes.as_ref())
+            .map_err(|_| CryptoError::Signature(signature_bytes.as_ref().to_vec()))?;
+
+        let key =
+            recover(&Message::parse(&message_hash), &signature, &recovery_id).map_err(|error| {
+                match error {
+                    Secp256k1Error::InvalidSignature => {
+                        CryptoError::Signature(signature_bytes.as_ref().to_vec())
+                    }
+                    _ => CryptoError::RecoverPreHash,
+                }
+            })?;
+
+        Ok(key.serialize().to_vec().into())
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "helpers")]
+mod tests {
+    use crate::{aptos::AptosCrypto, crypto::recovery_key_tests::run_all_testcases};
+
+    #[test]
+    fn test_aptos_crypto_impl() {
+        run_all_testcases::<AptosCrypto>();
+    }
+}
@@ -3,7 +3,17 @@
 //! Contains helper implementations of conversion between types used in Casper and this library.
 //! Implementation of the config suited for the casper network.
 
-use crate::{default_ext::DefaultCrypto, network::StdEnv, Bytes, RedStoneConfigImpl};
+use alloc::vec::Vec;
+
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped,
+};
+
+use crate::{
+    default_ext::DefaultCrypto, network::StdEnv, types::VALUE_SIZE, Bytes, FeedId,
+    RedStoneConfigImpl, SignerAddress, TimestampMillis, Value,
+};
 
 impl From<casper_types::bytesrepr::Bytes> for Bytes {
     fn from(value: casper_types::bytesrepr::Bytes) -> Self {
@@ -11,6 +21,69 @@ impl From<casper_types::bytesrepr::Bytes> for Bytes {
     }
 }
 
+/// Stores the 32-byte types as the Casper `ByteArray(32)`, so they can live directly in the contract storage.
+macro_rules! impl_casper_byte_array {
+    ($(
+        $type:ty: $as_bytes:ident, $from_array:path
+    );*) => {
+        $(
+            impl CLTyped for $type {
+                fn cl_type() -> CLType {
+                    CLType::ByteArray(VALUE_SIZE as u32)
+                }
+            }
+
+            impl ToBytes for $type {
+                fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+                    Ok(self.$as_bytes().to_vec())
+                }
+
+                fn serialized_length(&self) -> usize {
+                    VALUE_SIZE
+                }
+            }
+
+            impl FromBytes for $type {
+                fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+                    let (array, remainder) = <[u8; VALUE_SIZE]>::from_bytes(bytes)?;
+
+                    Ok(($from_array(array), remainder))
+                }
+            }
+        )*
+    };
+}
+impl_casper_byte_array!(
+    Value: as_be_bytes, Value;
+    FeedId: as_ref, FeedId::from;
+    SignerAddress: as_ref, SignerAddress::new
+);
+
+/// Stores the timestamp as the Casper `U64`.
+impl CLTyped for TimestampMillis {
+    fn cl_type() -> CLType {
+        u64::cl_type()
+    }
+}
+
+impl ToBytes for TimestampMillis {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        self.as_millis().to_bytes()
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.as_millis().serialized_length()
+    }
+}
+
+impl FromBytes for TimestampMillis {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (millis, remainder) = u64::from_bytes(bytes)?;
+
+        Ok((millis.into(), remainder))
+    }
+}
+
 pub type CasperRedStoneConfig = RedStoneConfigImpl<DefaultCrypto, StdEnv>;
 
 #[cfg(feature = "casper-test")]
@@ -33,3 +106,53 @@ pub mod casper_test {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use core::fmt::Debug;
+
+    use casper_types::{
+        bytesrepr::{self, FromBytes, ToBytes},
+        CLType, CLTyped, CLValue,
+    };
+
+    use crate::{FeedId, SignerAddress, TimestampMillis, Value};
+
+    fn assert_round_trip<T>(value: T, cl_type: CLType)
+    where
+        T: CLTyped + ToBytes + FromBytes + PartialEq + Debug + Clone,
+    {
+        let bytes = value.to_bytes().unwrap();
+
+        assert_eq!(bytes.len(), value.serialized_length());
+        assert_eq!(bytesrepr::deserialize::<T>(bytes), Ok(value.clone()));
+        assert_eq!(T::cl_type(), cl_type);
+
+        let cl_value = CLValue::from_t(value.clone()).unwrap();
+        assert_eq!(cl_value.into_t::<T>().unwrap(), value);
+    }
+
+    #[test]
+    fn test_casper_round_trip() {
+        let feed_id: FeedId = "ETH".parse().unwrap();
+        let signer: SignerAddress = "1ea62d73edF8ac05dfcea1a34b9796e937a29eFF".parse().unwrap();
+
+        assert_round_trip(Value::from(236389750361u128), CLType::ByteArray(32));
+        assert_round_trip(Value::max(), CLType::ByteArray(32));
+        assert_round_trip(feed_id, CLType::ByteArray(32));
+        assert_round_trip(signer, CLType::ByteArray(32));
+        assert_round_trip(TimestampMillis::from_millis(1707307760000), CLType::U64);
+    }
+
+    #[test]
+    fn test_casper_from_truncated_bytes() {
+        assert_eq!(
+            bytesrepr::deserialize::<Value>(vec![0; 31]),
+            Err(bytesrepr::Error::EarlyEndOfStream)
+        );
+        assert_eq!(
+            bytesrepr::deserialize::<TimestampMillis>(vec![0; 33]),
+            Err(bytesrepr::Error::LeftOverBytes)
+        );
+    }
+}
@@ -3,16 +3,25 @@
 //! See
 //! * [verify_untrusted_update] - for untrusted updaters
 //! * [verify_trusted_update] - for trusted updaters
+//! * [verify_trusted_update_with_min_time] - for trusted updaters, rate-limited
 //! * [verify_signers_config] - verify integrity of the config
+//! * [verify_data_staleness] - for verifying the data is not older than its time-to-live
+//! * [verify_data_staleness_batch] - for verifying the data of many feeds at once
 //! * [UpdateTimestampVerifier] - for verifying timestamps with static dispatch between Trusted/Untrusted source.
 
 use crate::{
-    network::error::Error, utils::slice::check_no_duplicates, SignerAddress, TimestampMillis,
+    network::error::Error, utils::slice::check_no_duplicates, FeedId, SignerAddress,
+    TimestampMillis,
 };
 
 /// Timestamp verifier, with variants for trusted/nontrusted updaters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum UpdateTimestampVerifier {
-    Trusted,
+    /// Trusted updaters are rate-limited by their own `min_time_between_updates`,
+    /// which is `0` by default, see [UpdateTimestampVerifier::with_trusted_min_time_between_updates].
+    Trusted {
+        min_time_between_updates: TimestampMillis,
+    },
     Untrusted,
 }
 
@@ -21,12 +30,38 @@ impl UpdateTimestampVerifier {
     ///  If yes, returns `Trusted` variant, `Untrusted` otherwise.
     pub fn verifier<T: PartialEq>(updater: &T, trusted: &[T]) -> Self {
         match trusted.contains(updater) {
-            true => UpdateTimestampVerifier::Trusted,
+            true => UpdateTimestampVerifier::Trusted {
+                min_time_between_updates: MIN_TIME_BETWEEN_UPDATES_FOR_TRUSTED,
+            },
             false => UpdateTimestampVerifier::Untrusted,
         }
     }
 
-    /// For trusted variant see [verify_trusted_update].
+    /// Returns `true` for the `Trusted` variant, e.g. for logging which verification path the update took.
+    pub fn is_trusted(&self) -> bool {
+        matches!(self, UpdateTimestampVerifier::Trusted { .. })
+    }
+
+    /// Returns `true` for the `Untrusted` variant.
+    pub fn is_untrusted(&self) -> bool {
+        !self.is_trusted()
+    }
+
+    /// Sets the `min_time_between_updates` of the `Trusted` variant, so the trusted updaters are rate-limited too.
+    /// The `Untrusted` variant is returned unchanged.
+    pub fn with_trusted_min_time_between_updates(
+        self,
+        min_time_between_updates: TimestampMillis,
+    ) -> Self {
+        match self {
+            UpdateTimestampVerifier::Trusted { .. } => UpdateTimestampVerifier::Trusted {
+                min_time_between_updates,
+            },
+            UpdateTimestampVerifier::Untrusted => UpdateTimestampVerifier::Untrusted,
+        }
+    }
+
+    /// For trusted variant see [verify_trusted_update_with_min_time].
     /// For untrusted variant see [verify_untrusted_update].
     pub fn verify_timestamp(
         &self,
@@ -37,9 +72,12 @@ impl UpdateTimestampVerifier {
         new_package_time: TimestampMillis,
     ) -> Result<(), Error> {
         match self {
-            UpdateTimestampVerifier::Trusted => verify_trusted_update(
+            UpdateTimestampVerifier::Trusted {
+                min_time_between_updates,
+            } => verify_trusted_update_with_min_time(
                 time_now,
                 last_write_time,
+                *min_time_between_updates,
                 last_package_time,
                 new_package_time,
             ),
@@ -54,7 +92,7 @@ impl UpdateTimestampVerifier {
     }
 }
 
-/// MIN_TIME_BETWEEN_UPDATES_FOR_TRUSTED is set to 0,
+/// MIN_TIME_BETWEEN_UPDATES_FOR_TRUSTED is set to 0 by default,
 /// since trusted can update as long as write timestamp is increasing.
 const MIN_TIME_BETWEEN_UPDATES_FOR_TRUSTED: TimestampMillis = TimestampMillis::from_millis(0);
 /// MAX_SIGNER_COUNT describes maximum number of signers in Config.
@@ -105,15 +143,31 @@ pub fn verify_trusted_update(
     last_package_time: TimestampMillis,
     new_package_time: TimestampMillis,
 ) -> Result<(), Error> {
-    verify_package_timestamp(last_package_time, new_package_time)?;
-
-    verify_write_timestamp(
+    verify_trusted_update_with_min_time(
         time_now,
         last_write_time,
         MIN_TIME_BETWEEN_UPDATES_FOR_TRUSTED,
+        last_package_time,
+        new_package_time,
     )
 }
 
+/// Verifies if:
+/// * Package timestamps are strictly increasing
+/// * This is the first write or the time between writes is strictly greater than `min_time_between_updates`,
+///   configured for the trusted updaters
+pub fn verify_trusted_update_with_min_time(
+    time_now: TimestampMillis,
+    last_write_time: Option<TimestampMillis>,
+    min_time_between_updates: TimestampMillis,
+    last_package_time: TimestampMillis,
+    new_package_time: TimestampMillis,
+) -> Result<(), Error> {
+    verify_package_timestamp(last_package_time, new_package_time)?;
+
+    verify_write_timestamp(time_now, last_write_time, min_time_between_updates)
+}
+
 /// Verifies if:
 /// * Package timestamps are strictly increasing
 /// * This is the first write or the time between writes is strictly greater than `min_time_between_updates`
@@ -129,6 +183,36 @@ pub fn verify_untrusted_update(
     verify_write_timestamp(time_now, last_write_time, min_time_between_updates)
 }
 
+/// Verifies if:
+/// * The data written at `data_time` is still valid at `time_now`, so no more than `data_ttl` passed in between.
+pub fn verify_data_staleness(
+    data_time: TimestampMillis,
+    time_now: TimestampMillis,
+    data_ttl: TimestampMillis,
+) -> Result<(), Error> {
+    if !data_time.add(data_ttl).is_same_or_after(time_now) {
+        return Err(Error::DataStaleness(data_time, time_now));
+    }
+
+    Ok(())
+}
+
+/// Verifies if:
+/// * The data of every feed, written at its `write_time`, is still valid at `time_now`,
+///   so no more than `data_ttl` passed in between.
+///
+/// Returns `Error::FeedDataStaleness` with the first stale feed.
+pub fn verify_data_staleness_batch(
+    entries: &[(FeedId, TimestampMillis)],
+    time_now: TimestampMillis,
+    data_ttl: TimestampMillis,
+) -> Result<(), Error> {
+    entries.iter().try_for_each(|(feed_id, write_time)| {
+        verify_data_staleness(*write_time, time_now, data_ttl)
+            .map_err(|_| Error::FeedDataStaleness(*feed_id, *write_time, time_now))
+    })
+}
+
 /// Verifies if:
 /// * signer list is non empty and contains at least `threshold` of elements.
 fn verify_signer_count_in_threshold(signers: &[SignerAddress], threshold: u8) -> Result<(), Error> {
@@ -169,8 +253,12 @@ pub fn verify_signers_config(signers: &[SignerAddress], threshold: u8) -> Result
 #[cfg(test)]
 mod tests {
     use crate::{
-        contract::verification::{verify_trusted_update, verify_untrusted_update},
+        contract::verification::{
+            verify_data_staleness, verify_data_staleness_batch, verify_trusted_update,
+            verify_untrusted_update, UpdateTimestampVerifier,
+        },
         network::error::Error,
+        FeedId,
     };
 
     #[test]
@@ -248,4 +336,124 @@ mod tests {
             ))
         );
     }
+
+    #[test]
+    fn verify_data_staleness_within_ttl_is_ok() -> Result<(), Error> {
+        verify_data_staleness(900.into(), 900.into(), 100.into())?;
+        verify_data_staleness(900.into(), 1000.into(), 100.into())
+    }
+
+    #[test]
+    fn verify_data_staleness_after_ttl_is_err() {
+        let res = verify_data_staleness(900.into(), 1001.into(), 100.into());
+
+        assert_eq!(res, Err(Error::DataStaleness(900.into(), 1001.into())));
+    }
+
+    #[test]
+    fn trusted_verifier_write_on_next_time_is_ok_by_default() -> Result<(), Error> {
+        UpdateTimestampVerifier::verifier(&1, &[1, 2]).verify_timestamp(
+            901.into(),
+            Some(900.into()),
+            100.into(),
+            0.into(),
+            1.into(),
+        )
+    }
+
+    #[test]
+    fn trusted_verifier_write_inside_configured_window_is_err() {
+        let res = UpdateTimestampVerifier::verifier(&1, &[1, 2])
+            .with_trusted_min_time_between_updates(10.into())
+            .verify_timestamp(910.into(), Some(900.into()), 100.into(), 0.into(), 1.into());
+
+        assert_eq!(
+            res,
+            Err(
+                Error::CurrentTimestampMustBeGreaterThanLatestUpdateTimestamp(
+                    910.into(),
+                    900.into()
+                )
+            )
+        );
+    }
+
+    #[test]
+    fn trusted_verifier_write_after_configured_window_is_ok() -> Result<(), Error> {
+        UpdateTimestampVerifier::verifier(&1, &[1, 2])
+            .with_trusted_min_time_between_updates(10.into())
+            .verify_timestamp(911.into(), Some(900.into()), 100.into(), 0.into(), 1.into())
+    }
+
+    #[test]
+    fn untrusted_verifier_ignores_trusted_min_time() {
+        let res = UpdateTimestampVerifier::verifier(&3, &[1, 2])
+            .with_trusted_min_time_between_updates(10.into())
+            .verify_timestamp(911.into(), Some(900.into()), 100.into(), 0.into(), 1.into());
+
+        assert_eq!(
+            res,
+            Err(
+                Error::CurrentTimestampMustBeGreaterThanLatestUpdateTimestamp(
+                    911.into(),
+                    900.into()
+                )
+            )
+        );
+    }
+
+    #[test]
+    fn verify_data_staleness_batch_fresh_is_ok() -> Result<(), Error> {
+        let entries = [
+            (FeedId::from(b"ETH".to_vec()), 900.into()),
+            (FeedId::from(b"BTC".to_vec()), 950.into()),
+        ];
+
+        verify_data_staleness_batch(&entries, 1000.into(), 100.into())?;
+        verify_data_staleness_batch(&[], 1000.into(), 100.into())
+    }
+
+    #[test]
+    fn verify_data_staleness_batch_reports_first_stale_feed() {
+        let entries = [
+            (FeedId::from(b"ETH".to_vec()), 950.into()),
+            (FeedId::from(b"BTC".to_vec()), 899.into()),
+            (FeedId::from(b"AVAX".to_vec()), 950.into()),
+            (FeedId::from(b"SOL".to_vec()), 800.into()),
+        ];
+
+        let res = verify_data_staleness_batch(&entries, 1000.into(), 100.into());
+
+        assert_eq!(
+            res,
+            Err(Error::FeedDataStaleness(
+                FeedId::from(b"BTC".to_vec()),
+                899.into(),
+                1000.into()
+            ))
+        );
+    }
+
+    #[test]
+    fn verifier_for_updater_in_trusted_set_is_trusted() {
+        let verifier = UpdateTimestampVerifier::verifier(&2, &[1, 2]);
+
+        assert!(verifier.is_trusted());
+        assert!(!verifier.is_untrusted());
+        assert_eq!(
+            verifier,
+            UpdateTimestampVerifier::Trusted {
+                min_time_between_updates: 0.into()
+            }
+        );
+    }
+
+    #[test]
+    fn verifier_for_updater_out_of_trusted_set_is_untrusted() {
+        let verifier = UpdateTimestampVerifier::verifier(&3, &[1, 2]);
+
+        assert!(verifier.is_untrusted());
+        assert!(!verifier.is_trusted());
+        assert_eq!(verifier, UpdateTimestampVerifier::Untrusted);
+    }
 }
@@ -1,14 +1,72 @@
 use alloc::vec::Vec;
+use core::convert::Infallible;
+
+use primitive_types::{U256, U512};
 
 use crate::{
-    core::{config::Config, validator::Validator},
+    core::{
+        config::{Aggregation, Config},
+        validator::Validator,
+    },
     network::error::Error,
     protocol::data_package::DataPackage,
     types::Value,
-    utils::median::Median,
+    utils::{filter::FilterSome, median::Median},
 };
 
-type Matrix = Vec<Vec<Option<Value>>>;
+/// Value signer matrix, stored as a single flat vector of `feed_count * signer_count` cells.
+///
+/// The values of a feed are laid out row after row, each row is indexed by the signer index,
+/// so the whole matrix takes one allocation regardless of the number of feeds.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Matrix {
+    values: Vec<Option<Value>>,
+    signer_count: usize,
+}
+
+impl Matrix {
+    fn new(feed_count: usize, signer_count: usize) -> Self {
+        Self {
+            values: vec![None; feed_count * signer_count],
+            signer_count,
+        }
+    }
+
+    fn feed_count(&self) -> usize {
+        if self.signer_count == 0 {
+            return 0;
+        }
+
+        self.values.len() / self.signer_count
+    }
+
+    fn cell_mut(&mut self, feed_index: usize, signer_index: usize) -> &mut Option<Value> {
+        &mut self.values[feed_index * self.signer_count + signer_index]
+    }
+
+    fn row(&self, feed_index: usize) -> &[Option<Value>] {
+        let start = feed_index * self.signer_count;
+
+        &self.values[start..start + self.signer_count]
+    }
+
+    fn rows(&self) -> impl Iterator<Item = &[Option<Value>]> {
+        (0..self.feed_count()).map(move |feed_index| self.row(feed_index))
+    }
+}
+
+#[cfg(test)]
+impl Matrix {
+    fn from_rows(rows: Vec<Vec<Option<Value>>>) -> Self {
+        let signer_count = rows.first().map_or(0, Vec::len);
+        assert!(rows.iter().all(|row| row.len() == signer_count));
+
+        Self {
+            values: rows.into_iter().flatten().collect(),
+            signer_count,
+        }
+    }
+}
 
 /// Aggregates values from a collection of data packages according to the provided configuration.
 ///
@@ -44,24 +102,127 @@ pub(crate) fn aggregate_values(
     aggregate_matrix(make_value_signer_matrix(config, data_packages)?, config)
 }
 
-fn aggregate_matrix(matrix: Matrix, config: &Config) -> Result<Vec<Value>, Error> {
+/// Aggregates the values of each feed independently, so an error of one feed doesn't hide the values of the others.
+///
+/// Returns the outcomes in the order of the `feed_ids` of the config:
+/// the feed with a reocurring data point fails with `Error::ReocuringFeedId`,
+/// the other feeds are aggregated like by the [`aggregate_values`].
+pub(crate) fn aggregate_values_lenient(
+    data_packages: Vec<DataPackage>,
+    config: &Config,
+) -> Vec<Result<Value, Error>> {
+    let mut reoccurring = vec![false; config.feed_ids().len()];
+    let matrix = fill_value_signer_matrix(config, data_packages, |feed_index, _| {
+        reoccurring[feed_index] = true;
+
+        Ok::<_, Infallible>(())
+    })
+    .unwrap_or_else(|never| match never {});
+
     matrix
+        .rows()
+        .enumerate()
+        .map(|(index, values)| {
+            if reoccurring[index] {
+                return Err(Error::ReocuringFeedId(config.feed_ids()[index]));
+            }
+
+            aggregate_row(config, index, values)
+        })
+        .collect()
+}
+
+/// Variant of the [`aggregate_values`] aggregating only the feeds of the given `feed_indices`, in their order.
+///
+/// The signer count threshold is checked only for these feeds, so the other feeds of the config
+/// missing in the data packages don't fail the aggregation.
+pub(crate) fn aggregate_values_for_feeds(
+    data_packages: Vec<DataPackage>,
+    config: &Config,
+    feed_indices: &[usize],
+) -> Result<Vec<Value>, Error> {
+    let matrix = make_value_signer_matrix(config, data_packages)?;
+
+    feed_indices
         .iter()
+        .map(|&index| aggregate_row(config, index, matrix.row(index)))
+        .collect()
+}
+
+/// Variant of the [`aggregate_values`] returning the spread of each feed along with its aggregated value.
+///
+/// The spread is the difference of the maximum and the minimum of the values contributing to the aggregated one.
+pub(crate) fn aggregate_values_detailed(
+    data_packages: Vec<DataPackage>,
+    config: &Config,
+) -> Result<Vec<(Value, Value)>, Error> {
+    make_value_signer_matrix(config, data_packages)?
+        .rows()
         .enumerate()
         .map(|(index, values)| {
-            let median = config
-                .validate_signer_count_threshold(index, values)?
-                .iter()
-                .map(|v| v.to_u256())
-                .collect::<Vec<_>>()
-                .median()
-                .ok_or(Error::ArrayIsEmpty)?;
+            let value = aggregate_row(config, index, values)?;
+            let values: Vec<_> = values.filter_some().iter().map(|v| v.to_u256()).collect();
+            let spread = match (values.iter().max(), values.iter().min()) {
+                (Some(&max), Some(&min)) => {
+                    Value::from_u256(max).saturating_sub(Value::from_u256(min))
+                }
+                _ => Value::from(0u8),
+            };
 
-            Ok(Value::from_u256(median))
+            Ok((value, spread))
         })
         .collect()
 }
 
+fn aggregate_matrix(matrix: Matrix, config: &Config) -> Result<Vec<Value>, Error> {
+    matrix
+        .rows()
+        .enumerate()
+        .map(|(index, values)| aggregate_row(config, index, values))
+        .collect()
+}
+
+fn aggregate_row(config: &Config, index: usize, values: &[Option<Value>]) -> Result<Value, Error> {
+    let values = config
+        .validate_signer_count_threshold(index, values)?
+        .iter()
+        .map(|v| v.to_u256())
+        .collect::<Vec<_>>();
+    let aggregated = match config.aggregation() {
+        Aggregation::Median => values.median(),
+        Aggregation::Mean => mean(&values),
+        Aggregation::Min => values.iter().min().copied(),
+        Aggregation::Max => values.iter().max().copied(),
+    }
+    .ok_or(Error::ArrayIsEmpty)?;
+
+    let feed_id = config.feed_ids()[index];
+    if let Some((min, max)) = config.value_bounds_of(feed_id) {
+        if aggregated < min.to_u256() || aggregated > max.to_u256() {
+            return Err(Error::ValueOutOfBounds(
+                feed_id,
+                Value::from_u256(aggregated),
+            ));
+        }
+    }
+
+    Ok(Value::from_u256(aggregated))
+}
+
+/// Returns the mean of the values, rounded down, summed in 512 bits so it doesn't overflow.
+fn mean(values: &[U256]) -> Option<U256> {
+    if values.is_empty() {
+        return None;
+    }
+
+    let sum = values
+        .iter()
+        .fold(U512::zero(), |sum, &value| sum + U512::from(value));
+
+    // the mean isn't greater than the maximum of the values, so it fits the 256 bits
+    U256::try_from(sum / U512::from(values.len())).ok()
+}
+
 /// Makes the value signer matrix.
 /// This function may fail if DataPackage contains DataPoints with reocuring FeedId
 /// or if FeedId has a wrong ASCII representation.
@@ -70,7 +231,24 @@ fn make_value_signer_matrix(
     config: &Config,
     data_packages: Vec<DataPackage>,
 ) -> Result<Matrix, Error> {
-    let mut matrix = vec![vec![None; config.signers().len()]; config.feed_ids().len()];
+    fill_value_signer_matrix(config, data_packages, |_, feed_id| {
+        Err(Error::ReocuringFeedId(feed_id))
+    })
+}
+
+/// Fills the value signer matrix, calling the `on_reoccurring` for the reocurring data points,
+/// which are skipped unless it fails.
+///
+/// The error type is generic, so the callers recording the reocurring data points can't fail.
+fn fill_value_signer_matrix<E>(
+    config: &Config,
+    data_packages: Vec<DataPackage>,
+    mut on_reoccurring: impl FnMut(usize, FeedId) -> Result<(), E>,
+) -> Result<Matrix, E> {
+    let signer_count = config.signers().len();
+    let mut matrix = Matrix::new(config.feed_ids().len(), signer_count);
+    // tracked apart from the matrix cells, as the skipped zero values leave their cells empty
+    let mut seen = vec![false; config.feed_ids().len() * signer_count];
 
     for data_package in data_packages.iter() {
         let Some(signer_index) = config.signer_index(&data_package.signer_address) else {
@@ -80,10 +258,16 @@ fn make_value_signer_matrix(
             let Some(feed_index) = config.feed_index(data_point.feed_id) else {
                 continue 'data_points_iter;
             };
-            if matrix[feed_index][signer_index].is_some() {
-                return Err(Error::ReocuringFeedId(data_point.feed_id));
+            let seen_cell = &mut seen[feed_index * signer_count + signer_index];
+            if *seen_cell {
+                on_reoccurring(feed_index, data_point.feed_id)?;
+                continue 'data_points_iter;
+            }
+            *seen_cell = true;
+            if !config.allow_zero_values() && data_point.value.to_u256().is_zero() {
+                continue 'data_points_iter;
             }
-            matrix[feed_index][signer_index] = data_point.value.into();
+            *matrix.cell_mut(feed_index, signer_index) = data_point.value.into();
         }
     }
 
@@ -93,13 +277,19 @@ fn make_value_signer_matrix(
 #[cfg(feature = "helpers")]
 #[cfg(test)]
 mod aggregate_matrix_tests {
+    use primitive_types::U256;
     #[cfg(target_arch = "wasm32")]
     use wasm_bindgen_test::wasm_bindgen_test as test;
 
     use crate::{
-        core::{aggregator::aggregate_matrix, config::Config},
+        core::{
+            aggregator::{aggregate_matrix, Matrix},
+            config::Config,
+            test_helpers::{from_ticker, BTC, ETH},
+        },
         helpers::iter_into::{IterInto, IterIntoOpt, OptIterIntoOpt},
         network::error::Error,
+        Value,
     };
 
     #[test]
@@ -118,12 +308,88 @@ mod aggregate_matrix_tests {
                 signer_count_threshold as u8,
             ));
 
-            let result = aggregate_matrix(matrix.clone(), &config);
+            let result = aggregate_matrix(Matrix::from_rows(matrix.clone()), &config);
 
             assert_eq!(result, Ok(vec![12u8, 22].iter_into()));
         }
     }
 
+    #[test]
+    fn test_aggregate_matrix_near_max_values() {
+        let max = U256::MAX;
+        let matrix = vec![
+            vec![
+                Value::from_u256(max).into(),
+                Value::from_u256(max - 2).into(),
+            ],
+            vec![
+                Value::from_u256(max).into(),
+                Value::from_u256(max - 1).into(),
+            ],
+        ];
+        let config = Config::test_with_signer_count_threshold_or_default(None);
+
+        let result = aggregate_matrix(Matrix::from_rows(matrix), &config);
+
+        assert_eq!(
+            result,
+            Ok(vec![Value::from_u256(max - 1), Value::from_u256(max - 1)])
+        );
+    }
+
+    #[test]
+    fn test_aggregate_matrix_within_value_bounds() {
+        let matrix = vec![
+            vec![11u8, 13].iter_into_opt(),
+            vec![21u8, 23].iter_into_opt(),
+        ];
+        let config = Config::test_with_signer_count_threshold_or_default(None)
+            .with_value_bounds(from_ticker(ETH), 12u8.into(), 12u8.into())
+            .with_value_bounds(from_ticker(BTC), 1u8.into(), 100u8.into());
+
+        let result = aggregate_matrix(Matrix::from_rows(matrix), &config);
+
+        assert_eq!(result, Ok(vec![12u8, 22].iter_into()));
+    }
+
+    #[test]
+    fn test_aggregate_matrix_out_of_value_bounds() {
+        let matrix = vec![
+            vec![11u8, 13].iter_into_opt(),
+            vec![21u8, 23].iter_into_opt(),
+        ];
+        let config = Config::test_with_signer_count_threshold_or_default(None)
+            .with_value_bounds(from_ticker(ETH), 1u8.into(), 100u8.into())
+            .with_value_bounds(from_ticker(BTC), 1u8.into(), 21u8.into());
+
+        let result = aggregate_matrix(Matrix::from_rows(matrix), &config);
+
+        assert_eq!(
+            result,
+            Err(Error::ValueOutOfBounds(from_ticker(BTC), 22u8.into()))
+        );
+    }
+
+    #[test]
+    fn test_aggregate_matrix_below_value_bounds() {
+        let matrix = vec![
+            vec![11u8, 13].iter_into_opt(),
+            vec![21u8, 23].iter_into_opt(),
+        ];
+        let config = Config::test_with_signer_count_threshold_or_default(None).with_value_bounds(
+            from_ticker(ETH),
+            13u8.into(),
+            100u8.into(),
+        );
+
+        let result = aggregate_matrix(Matrix::from_rows(matrix), &config);
+
+        assert_eq!(
+            result,
+            Err(Error::ValueOutOfBounds(from_ticker(ETH), 12u8.into()))
+        );
+    }
+
     #[test]
     fn test_aggregate_matrix_smaller_threshold_missing_one_value() {
         let config = Config::test_with_signer_count_threshold_or_default(Some(1));
@@ -133,7 +399,7 @@ mod aggregate_matrix_tests {
             vec![21u8.into(), None].opt_iter_into_opt(),
         ];
 
-        let result = aggregate_matrix(matrix, &config);
+        let result = aggregate_matrix(Matrix::from_rows(matrix), &config);
 
         assert_eq!(result, Ok(vec![12u8, 21].iter_into()));
     }
@@ -144,7 +410,7 @@ mod aggregate_matrix_tests {
 
         let matrix = vec![vec![11u8, 13].iter_into_opt(), vec![None; 2]];
 
-        let res = aggregate_matrix(matrix, &config);
+        let res = aggregate_matrix(Matrix::from_rows(matrix), &config);
 
         assert_eq!(res, Err(Error::ArrayIsEmpty))
     }
@@ -157,7 +423,7 @@ mod aggregate_matrix_tests {
         ];
 
         let config = Config::test_with_signer_count_threshold_or_default(None);
-        let res = aggregate_matrix(matrix, &config);
+        let res = aggregate_matrix(Matrix::from_rows(matrix), &config);
 
         assert_eq!(
             res,
@@ -169,7 +435,7 @@ mod aggregate_matrix_tests {
     fn test_aggregate_matrix_missing_whole_feed() {
         let matrix = vec![vec![11u8, 13].iter_into_opt(), vec![None; 2]];
         let config = Config::test_with_signer_count_threshold_or_default(None);
-        let res = aggregate_matrix(matrix, &config);
+        let res = aggregate_matrix(Matrix::from_rows(matrix), &config);
 
         assert_eq!(
             res,
@@ -191,6 +457,7 @@ mod make_value_signer_matrix {
             aggregator::{make_value_signer_matrix, Matrix},
             config::Config,
             test_helpers::{AVAX, BTC, ETH, TEST_SIGNER_ADDRESS_1, TEST_SIGNER_ADDRESS_2},
+            validator::Validator,
         },
         helpers::iter_into::IterInto,
         network::error::Error,
@@ -322,19 +589,180 @@ mod make_value_signer_matrix {
         expected_values: Vec<Vec<Option<u128>>>,
     ) -> Result<(), Error> {
         let config = &Config::test_with_signer_count_threshold_or_default(None);
-        let result = make_value_signer_matrix(config, data_packages)?;
+        let nested_result = make_nested_value_signer_matrix(config, data_packages.clone());
+        let result = make_value_signer_matrix(config, data_packages);
+
+        assert_eq!(result, nested_result.map(Matrix::from_rows));
+        let result = result?;
 
-        let expected_matrix: Matrix = expected_values
-            .iter()
-            .map(|row| {
-                row.iter()
-                    .map(|&value| value.map(Value::from))
-                    .collect::<Vec<_>>()
-            })
-            .collect();
+        let expected_matrix = Matrix::from_rows(
+            expected_values
+                .iter()
+                .map(|row| {
+                    row.iter()
+                        .map(|&value| value.map(Value::from))
+                        .collect::<Vec<_>>()
+                })
+                .collect(),
+        );
 
         assert_eq!(result, expected_matrix);
 
         Ok(())
     }
+
+    /// Reference implementation allocating a separate row per feed,
+    /// the flat matrix must produce exactly the same values.
+    fn make_nested_value_signer_matrix(
+        config: &Config,
+        data_packages: Vec<DataPackage>,
+    ) -> Result<Vec<Vec<Option<Value>>>, Error> {
+        let mut matrix = vec![vec![None; config.signers().len()]; config.feed_ids().len()];
+
+        for data_package in data_packages.iter() {
+            let Some(signer_index) = config.signer_index(&data_package.signer_address) else {
+                continue;
+            };
+            for data_point in data_package.data_points.iter() {
+                let Some(feed_index) = config.feed_index(data_point.feed_id) else {
+                    continue;
+                };
+                if matrix[feed_index][signer_index].is_some() {
+                    return Err(Error::ReocuringFeedId(data_point.feed_id));
+                }
+                matrix[feed_index][signer_index] = data_point.value.into();
+            }
+        }
+
+        Ok(matrix)
+    }
+}
+
+#[cfg(feature = "helpers")]
+#[cfg(test)]
+mod aggregate_values_tests {
+    use alloc::vec::Vec;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    use primitive_types::U256;
+
+    use crate::{
+        core::{
+            aggregator::{aggregate_values, mean},
+            config::{Aggregation, Config},
+            test_helpers::{
+                from_ticker, ETH, TEST_SIGNER_ADDRESS_1, TEST_SIGNER_ADDRESS_2,
+                TEST_SIGNER_ADDRESS_3,
+            },
+        },
+        network::error::Error,
+        protocol::data_package::DataPackage,
+    };
+
+    fn zero_value_data_packages(values: Vec<u128>) -> Vec<DataPackage> {
+        [
+            TEST_SIGNER_ADDRESS_1,
+            TEST_SIGNER_ADDRESS_2,
+            TEST_SIGNER_ADDRESS_3,
+        ]
+        .into_iter()
+        .zip(values)
+        .map(|(signer, value)| DataPackage::test_single_data_point(ETH, value, signer, None))
+        .collect()
+    }
+
+    fn zero_value_config(allow_zero_values: bool) -> Config {
+        Config::test(
+            None,
+            vec![
+                TEST_SIGNER_ADDRESS_1,
+                TEST_SIGNER_ADDRESS_2,
+                TEST_SIGNER_ADDRESS_3,
+            ],
+            vec![ETH],
+            None,
+            None,
+            None,
+        )
+        .with_allow_zero_values(allow_zero_values)
+    }
+
+    #[test]
+    fn test_aggregate_values_with_aggregation() {
+        for (aggregation, expected) in [
+            (Aggregation::Median, 20u8),
+            (Aggregation::Mean, 30),
+            (Aggregation::Min, 10),
+            (Aggregation::Max, 60),
+        ] {
+            let config = zero_value_config(true).with_aggregation(aggregation);
+
+            assert_eq!(
+                aggregate_values(zero_value_data_packages(vec![60, 10, 20]), &config),
+                Ok(vec![expected.into()]),
+                "{aggregation:?}"
+            );
+        }
+
+        let config = zero_value_config(true).with_aggregation(Aggregation::Mean);
+        assert_eq!(
+            aggregate_values(zero_value_data_packages(vec![10, 21]), &config),
+            Ok(vec![15u8.into()])
+        );
+    }
+
+    #[test]
+    fn test_mean_of_max_values() {
+        assert_eq!(mean(&[U256::MAX, U256::MAX]), Some(U256::MAX));
+        assert_eq!(mean(&[U256::MAX, U256::zero()]), Some(U256::MAX / 2));
+        assert_eq!(mean(&[]), None);
+    }
+
+    #[test]
+    fn test_aggregate_values_with_zero_value_allowed() {
+        let config = zero_value_config(true);
+
+        assert_eq!(
+            aggregate_values(zero_value_data_packages(vec![0, 10, 20]), &config),
+            Ok(vec![10u8.into()])
+        );
+        assert_eq!(
+            aggregate_values(zero_value_data_packages(vec![0, 12]), &config),
+            Ok(vec![6u8.into()])
+        );
+    }
+
+    #[test]
+    fn test_aggregate_values_with_zero_value_skipped() {
+        let config = zero_value_config(false);
+
+        assert_eq!(
+            aggregate_values(zero_value_data_packages(vec![0, 10, 20]), &config),
+            Ok(vec![15u8.into()])
+        );
+        assert_eq!(
+            aggregate_values(zero_value_data_packages(vec![0, 12]), &config),
+            Err(Error::InsufficientSignerCount(0, 1, from_ticker(ETH)))
+        );
+    }
+
+    #[test]
+    fn test_aggregate_values_with_reoccurring_zero_value_skipped() {
+        let config = zero_value_config(false);
+
+        for values in [vec![(ETH, 0), (ETH, 0)], vec![(ETH, 0), (ETH, 10)]] {
+            let data_packages = vec![DataPackage::test_multi_data_point(
+                values,
+                TEST_SIGNER_ADDRESS_1,
+                None,
+            )];
+
+            assert_eq!(
+                aggregate_values(data_packages, &config),
+                Err(Error::ReocuringFeedId(from_ticker(ETH)))
+            );
+        }
+    }
 }
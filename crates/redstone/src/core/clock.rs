@@ -0,0 +1,78 @@
+use crate::{core::config::Config, TimestampMillis};
+
+/// Source of the current time, the data package timestamps are validated against.
+///
+/// Lets the off-chain processors validate the payloads against the time of processing,
+/// instead of the `block_timestamp` frozen in the `Config`.
+pub trait Clock {
+    /// Returns the current time in milliseconds since the Unix epoch.
+    fn now(&self) -> TimestampMillis;
+}
+
+/// Clock always returning the same time, like the `block_timestamp` of the `Config`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FixedClock(pub TimestampMillis);
+
+impl Clock for FixedClock {
+    #[inline]
+    fn now(&self) -> TimestampMillis {
+        self.0
+    }
+}
+
+impl From<&Config> for FixedClock {
+    fn from(config: &Config) -> Self {
+        Self(*config.block_timestamp())
+    }
+}
+
+/// Clock returning the system time.
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+#[cfg(feature = "std")]
+impl Clock for SystemClock {
+    fn now(&self) -> TimestampMillis {
+        let millis = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_millis());
+
+        u64::try_from(millis).unwrap_or(u64::MAX).into()
+    }
+}
+
+#[cfg(feature = "helpers")]
+#[cfg(test)]
+mod tests {
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    use crate::core::{
+        clock::{Clock, FixedClock},
+        config::Config,
+        test_helpers::TEST_BLOCK_TIMESTAMP,
+    };
+
+    #[test]
+    fn test_fixed_clock() {
+        let config = Config::test_with_signer_count_threshold_or_default(None);
+
+        assert_eq!(FixedClock(42.into()).now(), 42.into());
+        assert_eq!(FixedClock::from(&config).now(), TEST_BLOCK_TIMESTAMP.into());
+    }
+
+    #[cfg(feature = "std")]
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn test_system_clock() {
+        use crate::core::clock::SystemClock;
+
+        let before = SystemClock.now();
+        let after = SystemClock.now();
+
+        // 2024-01-01T00:00:00Z
+        assert!(before.as_millis() > 1_704_067_200_000);
+        assert!(before.is_same_or_before(after));
+    }
+}
@@ -1,15 +1,46 @@
-use alloc::vec::Vec;
+use alloc::{string::String, vec::Vec};
 
 use derive_getters::Getters;
 
 use crate::{
     contract::verification::verify_signers_config,
     network::error::Error,
-    protocol::constants::{MAX_TIMESTAMP_AHEAD_MS, MAX_TIMESTAMP_DELAY_MS},
+    protocol::{
+        constants::{MAX_TIMESTAMP_AHEAD_MS, MAX_TIMESTAMP_DELAY_MS},
+        payload::Payload,
+    },
     utils::slice::check_no_duplicates,
-    FeedId, SignerAddress, TimestampMillis,
+    FeedId, SignerAddress, TimestampMillis, Value,
 };
 
+/// MAX_FEED_COUNT describes maximum number of feeds in Config, as the feed count is serialized in 2 bytes.
+const MAX_FEED_COUNT: usize = u16::MAX as usize;
+
+/// Strategy of aggregating the values of a feed, given by the different signers, into the single value.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Aggregation {
+    /// The median of the values, the average of the two middle ones for the even number of values.
+    #[default]
+    Median,
+    /// The arithmetic mean of the values, rounded down.
+    Mean,
+    /// The minimum of the values.
+    Min,
+    /// The maximum of the values.
+    Max,
+}
+
+/// Metadata of a data feed, reported along with its aggregated value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct FeedMeta {
+    /// The decimals of the aggregated value of the feed.
+    pub decimals: u8,
+
+    /// The human-readable description of the feed.
+    pub description: String,
+}
+
 /// Configuration for a RedStone payload processor.
 ///
 /// Specifies the parameters necessary for the verification and aggregation of values
@@ -48,6 +79,40 @@ pub struct Config {
     ///
     /// The value's been expressed in milliseconds since the Unix epoch (January 1, 1970).
     max_timestamp_ahead_ms: TimestampMillis,
+
+    /// The optional current time and the data time-to-live, used for rejecting the stale payloads.
+    ///
+    /// If set, the payload timestamp must not be older than the data time-to-live at the current time.
+    /// See [`Config::with_staleness`].
+    staleness: Option<(TimestampMillis, TimestampMillis)>,
+
+    /// The optional inclusive min/max bounds of the aggregated values, per data feed.
+    ///
+    /// The aggregated value of a feed out of its bounds fails the payload processing.
+    /// See [`Config::with_value_bounds`].
+    value_bounds: Vec<(FeedId, Value, Value)>,
+
+    /// Whether the zero values of the data points are included in the aggregation.
+    ///
+    /// The zero values are included by default, otherwise they're skipped like the missing data points,
+    /// so they don't count to the `signer_count_threshold`. See [`Config::with_allow_zero_values`].
+    allow_zero_values: bool,
+
+    /// The optional metadata of the data feeds, like their decimals and description,
+    /// carried through to the processing results. See [`Config::with_feed_meta`].
+    feed_meta: Vec<(FeedId, FeedMeta)>,
+
+    /// The strategy of aggregating the values of each of the feeds, the median by default.
+    /// See [`Config::with_aggregation`].
+    aggregation: Aggregation,
+
+    /// The signer indices in the `signers` list, sorted by the signer address for the binary search.
+    #[getter(skip)]
+    signer_indices: Vec<(SignerAddress, usize)>,
+
+    /// The feed indices in the `feed_ids` list, sorted by the feed id for the binary search.
+    #[getter(skip)]
+    feed_indices: Vec<(FeedId, usize)>,
 }
 
 impl Config {
@@ -70,6 +135,8 @@ impl Config {
     ///
     /// * Success `Self` if arguments to the functions are correct
     ///   or cresponding Err with `redstone::network::Error` otherwise.
+    ///   The `signer_count_threshold` greater than the number of `signers` fails with
+    ///   `Error::ConfigInsufficientSignerCount` up front, as no feed could be ever aggregated.
     pub fn try_new(
         signer_count_threshold: u8,
         signers: Vec<SignerAddress>,
@@ -78,27 +145,281 @@ impl Config {
         max_timestamp_delay_ms: Option<TimestampMillis>,
         max_timestamp_ahead_ms: Option<TimestampMillis>,
     ) -> Result<Self, Error> {
-        let config = Self {
+        DefaultWindows::default().try_new(
             signer_count_threshold,
             signers,
             feed_ids,
             block_timestamp,
-            max_timestamp_delay_ms: max_timestamp_delay_ms.unwrap_or(MAX_TIMESTAMP_DELAY_MS.into()),
-            max_timestamp_ahead_ms: max_timestamp_ahead_ms.unwrap_or(MAX_TIMESTAMP_AHEAD_MS.into()),
-        };
+            max_timestamp_delay_ms,
+            max_timestamp_ahead_ms,
+        )
+    }
 
-        config.verify_signer_list()?;
-        config.verify_feed_id_list()?;
+    /// Overrides the default timestamp windows, `MAX_TIMESTAMP_DELAY_MS` and `MAX_TIMESTAMP_AHEAD_MS`,
+    /// e.g. with the larger ones for the networks with slow finality.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_timestamp_delay_ms` - The default maximum delay of the package against the current block timestamp.
+    /// * `max_timestamp_ahead_ms` - The default maximum ahead of time of the package against current block timestamp.
+    ///
+    /// # Returns
+    ///
+    /// * `DefaultWindows` constructing the `Config`s with the windows not given per call set to the overridden ones.
+    pub fn with_default_windows(
+        max_timestamp_delay_ms: TimestampMillis,
+        max_timestamp_ahead_ms: TimestampMillis,
+    ) -> DefaultWindows {
+        DefaultWindows {
+            max_timestamp_delay_ms,
+            max_timestamp_ahead_ms,
+        }
+    }
 
-        Ok(config)
+    /// Shorter variant of the [`Config::try_new`], using the default timestamp windows.
+    ///
+    /// Fits the on-chain consumers storing only the signers and the threshold in the contract state
+    /// and rebuilding the `Config` each call. Runs the same validation as the [`Config::try_new`].
+    ///
+    /// # Arguments
+    ///
+    /// * `signer_count_threshold` - The minimum number of signers required validating the data.
+    /// * `signers` - List of identifiers for signers authorized to sign the data.
+    /// * `feed_ids` - Identifiers for the data feeds from which values are aggregated.
+    /// * `block_timestamp` - The current block time in timestamp format, used for verifying data timeliness.
+    ///
+    /// # Returns
+    ///
+    /// * Success `Self` if arguments to the functions are correct
+    ///   or cresponding Err with `redstone::network::Error` otherwise.
+    pub fn from_parts(
+        signer_count_threshold: u8,
+        signers: Vec<SignerAddress>,
+        feed_ids: Vec<FeedId>,
+        block_timestamp: TimestampMillis,
+    ) -> Result<Self, Error> {
+        Self::try_new(
+            signer_count_threshold,
+            signers,
+            feed_ids,
+            block_timestamp,
+            None,
+            None,
+        )
+    }
+
+    /// Returns the index of the signer in the `signers` list, searching the precomputed sorted indices.
+    #[inline]
+    pub(crate) fn cached_signer_index(&self, signer: &SignerAddress) -> Option<usize> {
+        find_sorted_index(&self.signer_indices, signer)
+    }
+
+    /// Returns the index of the feed in the `feed_ids` list, searching the precomputed sorted indices.
+    #[inline]
+    pub(crate) fn cached_feed_index(&self, feed_id: &FeedId) -> Option<usize> {
+        find_sorted_index(&self.feed_indices, feed_id)
+    }
+
+    /// Returns the index of the feed in the `feed_ids` list, like the `feed_index` of the `Validator`,
+    /// but fails with `Error::ConfigInvalidFeedId` carrying the feed id when it's missing.
+    pub fn require_feed_index(&self, feed_id: FeedId) -> Result<usize, Error> {
+        self.cached_feed_index(&feed_id)
+            .ok_or(Error::ConfigInvalidFeedId(feed_id))
+    }
+
+    /// Enables rejecting the stale payloads.
+    ///
+    /// # Arguments
+    ///
+    /// * `time_now` - The current time, the payload timestamp is checked against.
+    /// * `data_ttl` - The data time-to-live, the maximum age of the payload at the `time_now`.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` for which the payload processing fails with `Error::DataStaleness` for the stale payloads.
+    pub fn with_staleness(mut self, time_now: TimestampMillis, data_ttl: TimestampMillis) -> Self {
+        self.staleness = Some((time_now, data_ttl));
+
+        self
+    }
+
+    /// Sets the inclusive bounds of the aggregated value of the feed, replacing the previous ones.
+    ///
+    /// # Arguments
+    ///
+    /// * `feed_id` - The data feed the bounds apply to.
+    /// * `min` - The minimum allowed aggregated value.
+    /// * `max` - The maximum allowed aggregated value.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` for which the payload processing fails with `Error::ValueOutOfBounds`
+    ///   when the aggregated value of the feed is out of the bounds.
+    pub fn with_value_bounds(mut self, feed_id: FeedId, min: Value, max: Value) -> Self {
+        self.value_bounds
+            .retain(|(bounded_feed_id, _, _)| *bounded_feed_id != feed_id);
+        self.value_bounds.push((feed_id, min, max));
+
+        self
+    }
+
+    /// Sets whether the zero values of the data points are included in the aggregation.
+    ///
+    /// # Arguments
+    ///
+    /// * `allow_zero_values` - Whether to include the zero values, `false` skips them like the missing data points.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` for which the zero values are aggregated or skipped according to the `allow_zero_values`.
+    pub fn with_allow_zero_values(mut self, allow_zero_values: bool) -> Self {
+        self.allow_zero_values = allow_zero_values;
+
+        self
+    }
+
+    /// Sets the strategy of aggregating the values of each of the feeds.
+    ///
+    /// # Arguments
+    ///
+    /// * `aggregation` - The aggregation strategy, replacing the default median.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` for which the values of the feeds are aggregated with the `aggregation`.
+    pub fn with_aggregation(mut self, aggregation: Aggregation) -> Self {
+        self.aggregation = aggregation;
+
+        self
+    }
+
+    /// Sets the metadata of the feed, replacing the previous one.
+    ///
+    /// # Arguments
+    ///
+    /// * `feed_id` - The data feed the metadata describes.
+    /// * `feed_meta` - The metadata of the feed, like its decimals and description.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` for which the metadata is attached to the aggregated value of the feed
+    ///   by the [`crate::core::process_payload_with_meta`].
+    pub fn with_feed_meta(mut self, feed_id: FeedId, feed_meta: FeedMeta) -> Self {
+        self.feed_meta
+            .retain(|(described_feed_id, _)| *described_feed_id != feed_id);
+        self.feed_meta.push((feed_id, feed_meta));
+
+        self
+    }
+
+    /// Returns the metadata of the feed, if configured.
+    pub fn feed_meta_of(&self, feed_id: FeedId) -> Option<&FeedMeta> {
+        self.feed_meta
+            .iter()
+            .find(|(described_feed_id, _)| *described_feed_id == feed_id)
+            .map(|(_, feed_meta)| feed_meta)
+    }
+
+    /// Returns the inclusive bounds of the aggregated value of the feed, if configured.
+    pub fn value_bounds_of(&self, feed_id: FeedId) -> Option<(Value, Value)> {
+        self.value_bounds
+            .iter()
+            .find(|(bounded_feed_id, _, _)| *bounded_feed_id == feed_id)
+            .map(|(_, min, max)| (*min, *max))
+    }
+
+    /// Returns the inclusive range of the valid data package timestamps.
+    ///
+    /// The range spans from the `block_timestamp` delayed by the `max_timestamp_delay_ms`
+    /// to the `block_timestamp` ahead by the `max_timestamp_ahead_ms`, saturating at the bounds of the `u64`.
+    pub fn valid_timestamp_range(&self) -> (TimestampMillis, TimestampMillis) {
+        self.valid_timestamp_range_at(self.block_timestamp)
+    }
+
+    /// Variant of the [`Config::valid_timestamp_range`] around the given `block_timestamp`,
+    /// instead of the configured one.
+    pub fn valid_timestamp_range_at(
+        &self,
+        block_timestamp: TimestampMillis,
+    ) -> (TimestampMillis, TimestampMillis) {
+        let block_timestamp = block_timestamp.as_millis();
+
+        (
+            block_timestamp
+                .saturating_sub(self.max_timestamp_delay_ms.as_millis())
+                .into(),
+            block_timestamp
+                .saturating_add(self.max_timestamp_ahead_ms.as_millis())
+                .into(),
+        )
+    }
+
+    /// Splits the configured feeds into the ones having any data point in the `payload` and the absent ones,
+    /// as a pre-flight check before the aggregation.
+    ///
+    /// The data points of all the data packages are taken into account, regardless of their signers.
+    ///
+    /// # Returns
+    ///
+    /// * The `(present, absent)` feeds, both in the order of the `feed_ids`.
+    pub fn feeds_present_in(&self, payload: &Payload) -> (Vec<FeedId>, Vec<FeedId>) {
+        self.feed_ids.iter().copied().partition(|&feed_id| {
+            payload.data_packages.iter().any(|package| {
+                package
+                    .data_points
+                    .iter()
+                    .any(|data_point| data_point.feed_id == feed_id)
+            })
+        })
+    }
+
+    /// Returns the `requested` feeds without the aggregated value in the per-feed outcomes
+    /// of the [`crate::core::process_payload_lenient`], i.e. the feeds missing in the `Config`
+    /// and the ones failed to aggregate, like with `Error::InsufficientSignerCount`.
+    ///
+    /// # Arguments
+    ///
+    /// * `lenient_values` - The per-feed outcomes, in the order of the `feed_ids`.
+    /// * `requested` - The feeds requested by the consumer.
+    ///
+    /// # Returns
+    ///
+    /// * The missing feeds, in the order of the `requested` ones.
+    pub fn missing_feeds(
+        &self,
+        lenient_values: &[Result<Value, Error>],
+        requested: &[FeedId],
+    ) -> Vec<FeedId> {
+        requested
+            .iter()
+            .filter(|feed_id| {
+                self.cached_feed_index(feed_id)
+                    .and_then(|index| lenient_values.get(index))
+                    .map_or(true, Result::is_err)
+            })
+            .copied()
+            .collect()
     }
 
     #[inline]
     fn verify_feed_id_list(&self) -> Result<(), Error> {
         self.verify_feed_id_list_empty()?;
+        self.verify_feed_id_list_not_exceeded()?;
         check_no_duplicates(&self.feed_ids).map_err(Error::ConfigReocuringFeedId)
     }
 
+    #[inline(always)]
+    fn verify_feed_id_list_not_exceeded(&self) -> Result<(), Error> {
+        if self.feed_ids.len() > MAX_FEED_COUNT {
+            return Err(Error::ConfigExceededFeedCount(
+                self.feed_ids.len(),
+                MAX_FEED_COUNT,
+            ));
+        }
+
+        Ok(())
+    }
+
     #[inline(always)]
     fn verify_feed_id_list_empty(&self) -> Result<(), Error> {
         if self.feed_ids.is_empty() {
@@ -114,16 +435,112 @@ impl Config {
     }
 }
 
+/// The default timestamp windows of the `Config`, used when they aren't given to the constructor.
+///
+/// Defaults to the `MAX_TIMESTAMP_DELAY_MS` (15 minutes) and `MAX_TIMESTAMP_AHEAD_MS` (3 minutes),
+/// see [`Config::with_default_windows`] for overriding them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Getters)]
+pub struct DefaultWindows {
+    /// The maximum delay of the package used when none is given.
+    max_timestamp_delay_ms: TimestampMillis,
+
+    /// The maximum time package was created ahead used when none is given.
+    max_timestamp_ahead_ms: TimestampMillis,
+}
+
+impl Default for DefaultWindows {
+    fn default() -> Self {
+        Self {
+            max_timestamp_delay_ms: MAX_TIMESTAMP_DELAY_MS.into(),
+            max_timestamp_ahead_ms: MAX_TIMESTAMP_AHEAD_MS.into(),
+        }
+    }
+}
+
+impl DefaultWindows {
+    /// Variant of the [`Config::try_new`] falling back to these windows, instead of the crate defaults.
+    pub fn try_new(
+        &self,
+        signer_count_threshold: u8,
+        signers: Vec<SignerAddress>,
+        feed_ids: Vec<FeedId>,
+        block_timestamp: TimestampMillis,
+        max_timestamp_delay_ms: Option<TimestampMillis>,
+        max_timestamp_ahead_ms: Option<TimestampMillis>,
+    ) -> Result<Config, Error> {
+        let config = Config {
+            signer_count_threshold,
+            signer_indices: make_sorted_indices(&signers),
+            signers,
+            feed_indices: make_sorted_indices(&feed_ids),
+            feed_ids,
+            block_timestamp,
+            max_timestamp_delay_ms: max_timestamp_delay_ms.unwrap_or(self.max_timestamp_delay_ms),
+            max_timestamp_ahead_ms: max_timestamp_ahead_ms.unwrap_or(self.max_timestamp_ahead_ms),
+            staleness: None,
+            value_bounds: Vec::new(),
+            allow_zero_values: true,
+            feed_meta: Vec::new(),
+            aggregation: Aggregation::Median,
+        };
+
+        config.verify_signer_list()?;
+        config.verify_feed_id_list()?;
+
+        Ok(config)
+    }
+
+    /// Variant of the [`Config::from_parts`] using these windows, instead of the crate defaults.
+    pub fn from_parts(
+        &self,
+        signer_count_threshold: u8,
+        signers: Vec<SignerAddress>,
+        feed_ids: Vec<FeedId>,
+        block_timestamp: TimestampMillis,
+    ) -> Result<Config, Error> {
+        self.try_new(
+            signer_count_threshold,
+            signers,
+            feed_ids,
+            block_timestamp,
+            None,
+            None,
+        )
+    }
+}
+
+/// Pairs the items with their indices and sorts them by the item, for the [`find_sorted_index`].
+fn make_sorted_indices<T: Ord + Copy>(items: &[T]) -> Vec<(T, usize)> {
+    let mut indices: Vec<_> = items
+        .iter()
+        .enumerate()
+        .map(|(index, &item)| (item, index))
+        .collect();
+    indices.sort_unstable();
+
+    indices
+}
+
+#[inline]
+fn find_sorted_index<T: Ord>(indices: &[(T, usize)], item: &T) -> Option<usize> {
+    indices
+        .binary_search_by(|(sorted_item, _)| sorted_item.cmp(item))
+        .ok()
+        .map(|position| indices[position].1)
+}
+
 #[cfg(test)]
 #[cfg(feature = "helpers")]
 mod tests {
     use super::*;
     use crate::{
-        core::test_helpers::MAX_TIMESTAMP_DELAY_MS,
-        helpers::{
-            hex::{hex_to_bytes, make_feed_id},
-            iter_into::IterInto,
+        core::test_helpers::{
+            from_ticker, AVAX, BTC, ETH, MAX_TIMESTAMP_DELAY_MS, TEST_BLOCK_TIMESTAMP,
+            TEST_SIGNER_ADDRESS_1, TEST_SIGNER_ADDRESS_2,
         },
+        helpers::{hex::hex_to_bytes, iter_into::IterInto},
+        protocol::data_package::DataPackage,
+        types::VALUE_SIZE,
     };
 
     #[test]
@@ -139,6 +556,13 @@ mod tests {
             block_timestamp: 2000000000000.into(),
             max_timestamp_delay_ms: MAX_TIMESTAMP_AHEAD_MS.into(),
             max_timestamp_ahead_ms: MAX_TIMESTAMP_DELAY_MS.into(),
+            staleness: None,
+            value_bounds: Vec::new(),
+            allow_zero_values: true,
+            feed_meta: Vec::new(),
+            aggregation: Aggregation::Median,
+            signer_indices: Vec::new(),
+            feed_indices: Vec::new(),
         };
 
         config.verify_feed_id_list()
@@ -157,6 +581,13 @@ mod tests {
             block_timestamp: 2000000000000.into(),
             max_timestamp_delay_ms: MAX_TIMESTAMP_AHEAD_MS.into(),
             max_timestamp_ahead_ms: MAX_TIMESTAMP_DELAY_MS.into(),
+            staleness: None,
+            value_bounds: Vec::new(),
+            allow_zero_values: true,
+            feed_meta: Vec::new(),
+            aggregation: Aggregation::Median,
+            signer_indices: Vec::new(),
+            feed_indices: Vec::new(),
         };
 
         let resutlt = config.verify_feed_id_list();
@@ -178,13 +609,20 @@ mod tests {
             block_timestamp: 2000000000000.into(),
             max_timestamp_delay_ms: MAX_TIMESTAMP_AHEAD_MS.into(),
             max_timestamp_ahead_ms: MAX_TIMESTAMP_DELAY_MS.into(),
+            staleness: None,
+            value_bounds: Vec::new(),
+            allow_zero_values: true,
+            feed_meta: Vec::new(),
+            aggregation: Aggregation::Median,
+            signer_indices: Vec::new(),
+            feed_indices: Vec::new(),
         };
 
         let resutlt = config.verify_feed_id_list();
 
         assert_eq!(
             resutlt,
-            Err(Error::ConfigReocuringFeedId(make_feed_id(repeated_feed_id)))
+            Err(Error::ConfigReocuringFeedId(from_ticker(repeated_feed_id)))
         );
     }
 
@@ -204,6 +642,13 @@ mod tests {
             block_timestamp: 2000000000000.into(),
             max_timestamp_delay_ms: MAX_TIMESTAMP_AHEAD_MS.into(),
             max_timestamp_ahead_ms: MAX_TIMESTAMP_DELAY_MS.into(),
+            staleness: None,
+            value_bounds: Vec::new(),
+            allow_zero_values: true,
+            feed_meta: Vec::new(),
+            aggregation: Aggregation::Median,
+            signer_indices: Vec::new(),
+            feed_indices: Vec::new(),
         };
 
         config.verify_signer_list()
@@ -218,6 +663,13 @@ mod tests {
             block_timestamp: 2000000000000.into(),
             max_timestamp_delay_ms: MAX_TIMESTAMP_AHEAD_MS.into(),
             max_timestamp_ahead_ms: MAX_TIMESTAMP_DELAY_MS.into(),
+            staleness: None,
+            value_bounds: Vec::new(),
+            allow_zero_values: true,
+            feed_meta: Vec::new(),
+            aggregation: Aggregation::Median,
+            signer_indices: Vec::new(),
+            feed_indices: Vec::new(),
         };
 
         let resutlt = config.verify_signer_list();
@@ -241,6 +693,13 @@ mod tests {
             block_timestamp: 2000000000000.into(),
             max_timestamp_delay_ms: MAX_TIMESTAMP_AHEAD_MS.into(),
             max_timestamp_ahead_ms: MAX_TIMESTAMP_DELAY_MS.into(),
+            staleness: None,
+            value_bounds: Vec::new(),
+            allow_zero_values: true,
+            feed_meta: Vec::new(),
+            aggregation: Aggregation::Median,
+            signer_indices: Vec::new(),
+            feed_indices: Vec::new(),
         };
 
         let resutlt = config.verify_signer_list();
@@ -248,6 +707,28 @@ mod tests {
         assert_eq!(resutlt, Err(Error::ConfigInsufficientSignerCount(5, 6)));
     }
 
+    #[test]
+    fn test_config_threshold_above_signer_count() {
+        let result = Config::try_new(
+            5,
+            vec![
+                TEST_SIGNER_ADDRESS_1,
+                TEST_SIGNER_ADDRESS_2,
+                "dd34329d2fc551bea8ee480c2d35d09b75cea39e",
+            ]
+            .iter_into(),
+            vec![ETH].iter_into(),
+            TEST_BLOCK_TIMESTAMP.into(),
+            None,
+            None,
+        );
+
+        assert_eq!(
+            result.err(),
+            Some(Error::ConfigInsufficientSignerCount(3, 5))
+        );
+    }
+
     #[test]
     fn test_config_repeated_signers() {
         let repeated = "6809c0b4ab2fc9960c8fd6e5448ac9be10aa8fe3";
@@ -267,6 +748,13 @@ mod tests {
             block_timestamp: 2000000000000.into(),
             max_timestamp_delay_ms: MAX_TIMESTAMP_AHEAD_MS.into(),
             max_timestamp_ahead_ms: MAX_TIMESTAMP_DELAY_MS.into(),
+            staleness: None,
+            value_bounds: Vec::new(),
+            allow_zero_values: true,
+            feed_meta: Vec::new(),
+            aggregation: Aggregation::Median,
+            signer_indices: Vec::new(),
+            feed_indices: Vec::new(),
         };
 
         let resutlt = config.verify_signer_list();
@@ -294,6 +782,13 @@ mod tests {
             block_timestamp: 2000000000000.into(),
             max_timestamp_delay_ms: MAX_TIMESTAMP_AHEAD_MS.into(),
             max_timestamp_ahead_ms: MAX_TIMESTAMP_DELAY_MS.into(),
+            staleness: None,
+            value_bounds: Vec::new(),
+            allow_zero_values: true,
+            feed_meta: Vec::new(),
+            aggregation: Aggregation::Median,
+            signer_indices: Vec::new(),
+            feed_indices: Vec::new(),
         };
 
         let resutlt = config.verify_signer_list();
@@ -301,6 +796,190 @@ mod tests {
         assert_eq!(resutlt, Err(Error::ConfigExceededSignerCount(257, 255)));
     }
 
+    #[test]
+    fn test_config_exceeded_feed_count() {
+        let feed_ids = (0..=MAX_FEED_COUNT)
+            .map(|index| {
+                let mut feed_id = [0; VALUE_SIZE];
+                feed_id[..8].copy_from_slice(&(index as u64).to_be_bytes());
+
+                FeedId::from(feed_id)
+            })
+            .collect();
+
+        let result = Config::try_new(
+            1,
+            vec![TEST_SIGNER_ADDRESS_1].iter_into(),
+            feed_ids,
+            TEST_BLOCK_TIMESTAMP.into(),
+            None,
+            None,
+        );
+
+        assert_eq!(
+            result.err(),
+            Some(Error::ConfigExceededFeedCount(65536, MAX_FEED_COUNT))
+        );
+    }
+
+    #[test]
+    fn test_feeds_present_in() {
+        let config = Config::test(
+            None,
+            vec![TEST_SIGNER_ADDRESS_1, TEST_SIGNER_ADDRESS_2],
+            vec![ETH, BTC, AVAX],
+            None,
+            None,
+            None,
+        );
+        let payload = Payload {
+            data_packages: vec![
+                DataPackage::test_single_data_point(AVAX, 31, TEST_SIGNER_ADDRESS_1, None),
+                DataPackage::test_single_data_point(ETH, 11, TEST_SIGNER_ADDRESS_2, None),
+            ],
+        };
+
+        assert_eq!(
+            config.feeds_present_in(&payload),
+            (
+                vec![from_ticker(ETH), from_ticker(AVAX)],
+                vec![from_ticker(BTC)]
+            )
+        );
+    }
+
+    #[test]
+    fn test_require_feed_index() {
+        let config = Config::test_with_signer_count_threshold_or_default(None);
+
+        assert_eq!(config.require_feed_index(from_ticker(BTC)), Ok(1));
+        assert_eq!(
+            config.require_feed_index(from_ticker(AVAX)),
+            Err(Error::ConfigInvalidFeedId(from_ticker(AVAX)))
+        );
+    }
+
+    #[test]
+    fn test_valid_timestamp_range() {
+        let config = Config::test_with_signer_count_threshold_or_default(None);
+
+        assert_eq!(
+            config.valid_timestamp_range(),
+            (
+                (TEST_BLOCK_TIMESTAMP - MAX_TIMESTAMP_DELAY_MS).into(),
+                (TEST_BLOCK_TIMESTAMP + MAX_TIMESTAMP_AHEAD_MS).into()
+            )
+        );
+    }
+
+    #[test]
+    fn test_valid_timestamp_range_saturating() {
+        let config = Config::try_new(
+            1,
+            vec!["dd34329d2fc551bea8ee480c2d35d09b75cea39e"].iter_into(),
+            vec!["ETH"].iter_into(),
+            1000.into(),
+            Some(2000.into()),
+            Some(u64::MAX.into()),
+        )
+        .unwrap();
+
+        assert_eq!(config.valid_timestamp_range(), (0.into(), u64::MAX.into()));
+    }
+
+    #[test]
+    fn test_config_from_parts() {
+        let signers: Vec<SignerAddress> = vec![
+            "dd34329d2fc551bea8ee480c2d35d09b75cea39e",
+            "582ad60bedebfc21cfee1e1cb025cd2c77fc2bf4",
+        ]
+        .iter_into();
+        let feed_ids: Vec<FeedId> = vec!["ETH", "BTC"].iter_into();
+
+        let config = Config::from_parts(
+            2,
+            signers.clone(),
+            feed_ids.clone(),
+            TEST_BLOCK_TIMESTAMP.into(),
+        )
+        .unwrap();
+        let expected = Config::try_new(
+            2,
+            signers.clone(),
+            feed_ids.clone(),
+            TEST_BLOCK_TIMESTAMP.into(),
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.signer_count_threshold(),
+            expected.signer_count_threshold()
+        );
+        assert_eq!(config.signers(), expected.signers());
+        assert_eq!(config.feed_ids(), expected.feed_ids());
+        assert_eq!(config.block_timestamp(), expected.block_timestamp());
+        assert_eq!(
+            config.max_timestamp_delay_ms(),
+            expected.max_timestamp_delay_ms()
+        );
+        assert_eq!(
+            config.max_timestamp_ahead_ms(),
+            expected.max_timestamp_ahead_ms()
+        );
+
+        assert_eq!(
+            Config::from_parts(3, signers, feed_ids, TEST_BLOCK_TIMESTAMP.into()).map(|_| ()),
+            Err(Error::ConfigInsufficientSignerCount(2, 3))
+        );
+    }
+
+    #[test]
+    fn test_config_with_default_windows() {
+        let windows = Config::with_default_windows(3_600_000.into(), 600_000.into());
+        let signers: Vec<SignerAddress> =
+            vec!["dd34329d2fc551bea8ee480c2d35d09b75cea39e"].iter_into();
+        let feed_ids: Vec<FeedId> = vec!["ETH"].iter_into();
+
+        let config = windows
+            .from_parts(
+                1,
+                signers.clone(),
+                feed_ids.clone(),
+                TEST_BLOCK_TIMESTAMP.into(),
+            )
+            .unwrap();
+
+        assert_eq!(*config.max_timestamp_delay_ms(), 3_600_000.into());
+        assert_eq!(*config.max_timestamp_ahead_ms(), 600_000.into());
+
+        let config = windows
+            .try_new(
+                1,
+                signers,
+                feed_ids,
+                TEST_BLOCK_TIMESTAMP.into(),
+                Some(1000.into()),
+                None,
+            )
+            .unwrap();
+
+        assert_eq!(*config.max_timestamp_delay_ms(), 1000.into());
+        assert_eq!(*config.max_timestamp_ahead_ms(), 600_000.into());
+    }
+
+    #[test]
+    fn test_default_windows() {
+        assert_eq!(
+            DefaultWindows::default(),
+            Config::with_default_windows(
+                MAX_TIMESTAMP_DELAY_MS.into(),
+                MAX_TIMESTAMP_AHEAD_MS.into()
+            )
+        );
+    }
+
     fn helper_generate_random_hex(size: usize) -> Vec<u8> {
         let mut data: Vec<u8> = vec![0u8; size];
         for x in data.iter_mut() {
@@ -0,0 +1,241 @@
+use alloc::vec::Vec;
+
+use crate::{
+    core::config::Config, network::error::Error, types::VALUE_SIZE, FeedId, SignerAddress,
+    TimestampMillis,
+};
+
+/// Version of the binary layout of the `Config`, see [`Config::to_bytes`].
+pub const CONFIG_BYTES_VERSION: u8 = 1;
+
+const VERSION_BS: usize = 1;
+const SIGNER_COUNT_THRESHOLD_BS: usize = 1;
+const SIGNER_COUNT_BS: usize = 1;
+const FEED_COUNT_BS: usize = 2;
+const TIMESTAMP_BS: usize = 8;
+
+impl Config {
+    /// Serializes the config to the compact binary layout, for storing it in the contract storage.
+    ///
+    /// The layout (version 1) consists of the big-endian fields:
+    /// * the version byte, equal to the [`CONFIG_BYTES_VERSION`],
+    /// * the `signer_count_threshold` byte,
+    /// * the signer count byte followed by the 32-byte signer addresses,
+    /// * the 2-byte feed count followed by the 32-byte feed ids,
+    /// * the 8-byte `block_timestamp`, `max_timestamp_delay_ms` and `max_timestamp_ahead_ms`.
+    ///
+    /// The optional settings, like the staleness or the value bounds, aren't serialized.
+    /// The counts always fit their fields, as the [`Config::try_new`] rejects the configs
+    /// of more than 255 signers or 65535 feeds.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(Self::serialized_size(
+            self.signers().len(),
+            self.feed_ids().len(),
+        ));
+
+        bytes.push(CONFIG_BYTES_VERSION);
+        bytes.push(*self.signer_count_threshold());
+        bytes.push(self.signers().len() as u8);
+        for signer in self.signers() {
+            bytes.extend_from_slice(signer.as_ref());
+        }
+        bytes.extend_from_slice(&(self.feed_ids().len() as u16).to_be_bytes());
+        for feed_id in self.feed_ids() {
+            bytes.extend_from_slice(feed_id.as_ref());
+        }
+        for timestamp in [
+            self.block_timestamp(),
+            self.max_timestamp_delay_ms(),
+            self.max_timestamp_ahead_ms(),
+        ] {
+            bytes.extend_from_slice(&timestamp.as_millis().to_be_bytes());
+        }
+
+        bytes
+    }
+
+    /// Returns the exact size of the bytes of the [`Config::to_bytes`] for the config
+    /// of `signer_count` signers and `feed_count` feeds, for pre-sizing the contract storage.
+    pub const fn serialized_size(signer_count: usize, feed_count: usize) -> usize {
+        VERSION_BS
+            + SIGNER_COUNT_THRESHOLD_BS
+            + SIGNER_COUNT_BS
+            + signer_count * VALUE_SIZE
+            + FEED_COUNT_BS
+            + feed_count * VALUE_SIZE
+            + 3 * TIMESTAMP_BS
+    }
+
+    /// Deserializes the config from the binary layout of the [`Config::to_bytes`].
+    ///
+    /// Fails with `Error::ConfigMalformedBytes` carrying the offset of the malformed part,
+    /// for the unknown version, the truncated bytes or the bytes left after the config,
+    /// and with the errors of the [`Config::try_new`] for the invalid config.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Config, Error> {
+        let mut reader = Reader { bytes, offset: 0 };
+
+        if reader.take_array::<VERSION_BS>()? != [CONFIG_BYTES_VERSION] {
+            return Err(Error::ConfigMalformedBytes(0));
+        }
+        let [signer_count_threshold] = reader.take_array::<SIGNER_COUNT_THRESHOLD_BS>()?;
+        let [signer_count] = reader.take_array::<SIGNER_COUNT_BS>()?;
+        let signers = (0..signer_count)
+            .map(|_| reader.take_array().map(SignerAddress::new))
+            .collect::<Result<Vec<_>, _>>()?;
+        let feed_count = u16::from_be_bytes(reader.take_array::<FEED_COUNT_BS>()?);
+        let feed_ids = (0..feed_count)
+            .map(|_| reader.take_array::<VALUE_SIZE>().map(FeedId::from))
+            .collect::<Result<Vec<_>, _>>()?;
+        let block_timestamp = reader.take_timestamp()?;
+        let max_timestamp_delay_ms = reader.take_timestamp()?;
+        let max_timestamp_ahead_ms = reader.take_timestamp()?;
+
+        if reader.offset != bytes.len() {
+            return Err(Error::ConfigMalformedBytes(reader.offset));
+        }
+
+        Config::try_new(
+            signer_count_threshold,
+            signers,
+            feed_ids,
+            block_timestamp,
+            Some(max_timestamp_delay_ms),
+            Some(max_timestamp_ahead_ms),
+        )
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl Reader<'_> {
+    fn take_array<const N: usize>(&mut self) -> Result<[u8; N], Error> {
+        let array = self
+            .bytes
+            .get(self.offset..self.offset + N)
+            .and_then(|slice| slice.try_into().ok())
+            .ok_or(Error::ConfigMalformedBytes(self.offset))?;
+        self.offset += N;
+
+        Ok(array)
+    }
+
+    fn take_timestamp(&mut self) -> Result<TimestampMillis, Error> {
+        Ok(u64::from_be_bytes(self.take_array::<TIMESTAMP_BS>()?).into())
+    }
+}
+
+#[cfg(feature = "helpers")]
+#[cfg(test)]
+mod tests {
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    use crate::{
+        core::{
+            config::Config,
+            config_bytes::CONFIG_BYTES_VERSION,
+            test_helpers::{
+                AVAX, BTC, ETH, TEST_SIGNER_ADDRESS_1, TEST_SIGNER_ADDRESS_2, TEST_SIGNER_ADDRESS_3,
+            },
+        },
+        network::error::Error,
+    };
+
+    fn assert_round_trip(config: Config) {
+        let bytes = config.to_bytes();
+        let decoded = Config::from_bytes(&bytes).unwrap();
+
+        assert_eq!(bytes[0], CONFIG_BYTES_VERSION);
+        assert_eq!(
+            decoded.signer_count_threshold(),
+            config.signer_count_threshold()
+        );
+        assert_eq!(decoded.signers(), config.signers());
+        assert_eq!(decoded.feed_ids(), config.feed_ids());
+        assert_eq!(decoded.block_timestamp(), config.block_timestamp());
+        assert_eq!(
+            decoded.max_timestamp_delay_ms(),
+            config.max_timestamp_delay_ms()
+        );
+        assert_eq!(
+            decoded.max_timestamp_ahead_ms(),
+            config.max_timestamp_ahead_ms()
+        );
+        assert_eq!(decoded.to_bytes(), bytes);
+    }
+
+    #[test]
+    fn test_config_bytes_round_trip() {
+        assert_round_trip(Config::test_with_signer_count_threshold_or_default(None));
+        assert_round_trip(Config::test(
+            Some(1),
+            vec![
+                TEST_SIGNER_ADDRESS_1,
+                TEST_SIGNER_ADDRESS_2,
+                TEST_SIGNER_ADDRESS_3,
+            ],
+            vec![ETH, BTC, AVAX],
+            Some(1234.into()),
+            Some(5.into()),
+            Some(6.into()),
+        ));
+    }
+
+    #[test]
+    fn test_config_serialized_size() {
+        let signers = [
+            TEST_SIGNER_ADDRESS_1,
+            TEST_SIGNER_ADDRESS_2,
+            TEST_SIGNER_ADDRESS_3,
+        ];
+        let feed_ids = [ETH, BTC, AVAX];
+
+        for signer_count in 1..=signers.len() {
+            for feed_count in 1..=feed_ids.len() {
+                let config = Config::test(
+                    Some(1),
+                    signers[..signer_count].to_vec(),
+                    feed_ids[..feed_count].to_vec(),
+                    None,
+                    None,
+                    None,
+                );
+
+                assert_eq!(
+                    config.to_bytes().len(),
+                    Config::serialized_size(signer_count, feed_count)
+                );
+            }
+        }
+        assert_eq!(Config::serialized_size(2, 2), 157);
+    }
+
+    #[test]
+    fn test_config_bytes_malformed() {
+        let bytes = Config::test_with_signer_count_threshold_or_default(None).to_bytes();
+
+        assert_eq!(
+            Config::from_bytes(&[]).err(),
+            Some(Error::ConfigMalformedBytes(0))
+        );
+        assert_eq!(
+            Config::from_bytes(&[&[2][..], &bytes[1..]].concat()).err(),
+            Some(Error::ConfigMalformedBytes(0))
+        );
+        assert_eq!(
+            Config::from_bytes(&bytes[..bytes.len() - 1]).err(),
+            Some(Error::ConfigMalformedBytes(bytes.len() - 8))
+        );
+        assert_eq!(
+            Config::from_bytes(&[&bytes[..], &[0]].concat()).err(),
+            Some(Error::ConfigMalformedBytes(bytes.len()))
+        );
+        assert_eq!(
+            Config::from_bytes(&[&[CONFIG_BYTES_VERSION, 3][..], &bytes[2..]].concat()).err(),
+            Some(Error::ConfigInsufficientSignerCount(2, 3))
+        );
+    }
+}
@@ -1,13 +1,23 @@
+pub mod clock;
 pub mod config;
+pub mod config_bytes;
 pub mod processor;
 pub mod processor_result;
 
 mod aggregator;
 pub mod validator;
 
-pub use processor::process_payload;
+#[cfg(feature = "rayon")]
+pub use processor::decode_payload_sequential;
+#[cfg(feature = "helpers")]
+pub use processor::process_payload_hex;
+pub use processor::{
+    decode_payload, process_decoded_payload, process_payload, process_payload_at,
+    process_payload_detailed, process_payload_for_feeds, process_payload_full,
+    process_payload_lenient, process_payload_with_clock, process_payload_with_meta,
+};
 pub use processor_result::ProcessorResult;
 
 #[cfg(feature = "helpers")]
 #[cfg(test)]
-mod test_helpers;
+pub(crate) mod test_helpers;
@@ -1,12 +1,25 @@
+use alloc::{collections::BTreeSet, vec::Vec};
+use core::fmt::Debug;
+
+#[cfg(feature = "helpers")]
+use crate::utils::hex::try_hex_to_bytes;
+#[cfg(feature = "async-crypto")]
+use crate::AsyncCrypto;
 use crate::{
+    contract::verification::verify_data_staleness,
     core::{
-        aggregator::aggregate_values,
+        aggregator::{
+            aggregate_values, aggregate_values_detailed, aggregate_values_for_feeds,
+            aggregate_values_lenient,
+        },
+        clock::{Clock, FixedClock},
         config::Config,
-        processor_result::{ProcessorResult, ValidatedPayload},
+        processor_result::{DescribedPayload, DetailedPayload, ProcessorResult, ValidatedPayload},
+        validator::{AtBlockTimestamp, Validator},
     },
-    network::Environment,
-    protocol::{payload::Payload, PayloadDecoder},
-    Bytes, RedStoneConfig,
+    network::{error::Error, log_event, Environment, LogEvent, LogLevel},
+    protocol::{data_package::DataPackage, payload::Payload, PayloadDecoder},
+    Bytes, FeedId, RedStoneConfig, TimestampMillis, Value,
 };
 
 /// The main processor of the RedStone payload.
@@ -19,7 +32,7 @@ use crate::{
 ///
 /// # Returns
 ///
-/// * Returns a `ProcessorResult` in case of successful payload processing. Will panic in case of bad input.
+/// * Returns a `ProcessorResult` in case of successful payload processing, or the `Error` of the processing.
 pub fn process_payload(
     config: &impl RedStoneConfig,
     payload_bytes: impl Into<Bytes>,
@@ -27,38 +40,502 @@ pub fn process_payload(
     config.process_payload(payload_bytes)
 }
 
+/// Variant of the [`process_payload`] validating the data package timestamps against the given `block_timestamp`
+/// instead of the one stored in the `Config`.
+///
+/// Lets a long-lived `Config` be reused across the blocks, without being rebuilt for each block timestamp.
+///
+/// # Arguments
+///
+/// * `config` - Something that implements `RedStoneConfig`. Provides environment and crypto operations.
+/// * `payload_bytes` - Network-specific byte-list of the payload to be processed.
+/// * `block_timestamp` - The current block time, the data package timestamps are validated against.
+///
+/// # Returns
+///
+/// * Returns a `ProcessorResult` in case of successful payload processing, or the `Error` of the processing.
+pub fn process_payload_at<T: RedStoneConfig>(
+    config: &T,
+    payload_bytes: impl Into<Bytes>,
+    block_timestamp: TimestampMillis,
+) -> ProcessorResult {
+    let payload = decode_payload(config, payload_bytes)?;
+
+    make_processor_result_at::<T::Environment>(config.config(), block_timestamp, payload)
+}
+
+/// Variant of the [`process_payload`] validating the data package timestamps against the current time of the `clock`.
+///
+/// The [`process_payload`] is the same as this function with the [`FixedClock`] of the `block_timestamp` of the `Config`.
+///
+/// # Arguments
+///
+/// * `config` - Something that implements `RedStoneConfig`. Provides environment and crypto operations.
+/// * `payload_bytes` - Network-specific byte-list of the payload to be processed.
+/// * `clock` - The source of the current time, the data package timestamps are validated against.
+///
+/// # Returns
+///
+/// * Returns a `ProcessorResult` in case of successful payload processing, or the `Error` of the processing.
+pub fn process_payload_with_clock<T: RedStoneConfig>(
+    config: &T,
+    payload_bytes: impl Into<Bytes>,
+    clock: &impl Clock,
+) -> ProcessorResult {
+    process_payload_at(config, payload_bytes, clock.now())
+}
+
+/// Variant of the [`process_payload`] aggregating each of the feeds independently,
+/// so an error of one feed doesn't hide the values of the others.
+///
+/// The payload-wide checks, like the decoding, the timestamp validation and the data staleness, still fail the whole call.
+///
+/// # Arguments
+///
+/// * `config` - Something that implements `RedStoneConfig`. Provides environment and crypto operations.
+/// * `payload_bytes` - Network-specific byte-list of the payload to be processed.
+///
+/// # Returns
+///
+/// * Returns the per-feed outcomes, in the order of the `feed_ids` of the config,
+///   or the `Error` of the payload-wide checks.
+pub fn process_payload_lenient<T: RedStoneConfig>(
+    config: &T,
+    payload_bytes: impl Into<Bytes>,
+) -> Result<Vec<Result<Value, Error>>, Error> {
+    let payload = decode_payload(config, payload_bytes)?;
+
+    make_lenient_values::<T::Environment>(config.config(), payload)
+}
+
+/// Variant of the [`process_payload`] returning also the spread of each of the feeds,
+/// i.e. the difference of the maximum and the minimum of the values contributing to the aggregated one.
+///
+/// # Arguments
+///
+/// * `config` - Something that implements `RedStoneConfig`. Provides environment and crypto operations.
+/// * `payload_bytes` - Network-specific byte-list of the payload to be processed.
+///
+/// # Returns
+///
+/// * Returns a `DetailedPayload` in case of successful payload processing, or the `Error` of the processing.
+pub fn process_payload_detailed<T: RedStoneConfig>(
+    config: &T,
+    payload_bytes: impl Into<Bytes>,
+) -> Result<DetailedPayload, Error> {
+    let payload = decode_payload(config, payload_bytes)?;
+
+    make_detailed_processor_result::<T::Environment>(config.config(), payload)
+}
+
+/// Variant of the [`process_payload`] returning also the metadata of each of the feeds,
+/// set with the [`Config::with_feed_meta`], so the consumers don't need their own lookup of it.
+///
+/// # Arguments
+///
+/// * `config` - Something that implements `RedStoneConfig`. Provides environment and crypto operations.
+/// * `payload_bytes` - Network-specific byte-list of the payload to be processed.
+///
+/// # Returns
+///
+/// * Returns a `DescribedPayload` in case of successful payload processing, or the `Error` of the processing.
+pub fn process_payload_with_meta<T: RedStoneConfig>(
+    config: &T,
+    payload_bytes: impl Into<Bytes>,
+) -> Result<DescribedPayload, Error> {
+    let validated_payload = process_payload(config, payload_bytes)?;
+    let feed_meta = config
+        .config()
+        .feed_ids()
+        .iter()
+        .map(|&feed_id| config.config().feed_meta_of(feed_id).cloned())
+        .collect();
+
+    Ok(DescribedPayload {
+        validated_payload,
+        feed_meta,
+    })
+}
+
+/// Variant of the [`process_payload`] returning the values of the requested `feed_ids` only, in their order.
+///
+/// The requested feeds are resolved against the `Config` before the payload is decoded,
+/// so a feed missing in the `Config` fails fast with `Error::ConfigInvalidFeedId` carrying its id.
+/// Only the signers of the data packages containing any of the `feed_ids` are recovered,
+/// and the signer count threshold is checked only for the `feed_ids`,
+/// so the other feeds of the `Config` missing in the payload don't fail the call.
+///
+/// # Arguments
+///
+/// * `config` - Something that implements `RedStoneConfig`. Provides environment and crypto operations.
+/// * `payload_bytes` - Network-specific byte-list of the payload to be processed.
+/// * `feed_ids` - The feeds to read the aggregated values of.
+///
+/// # Returns
+///
+/// * Returns the aggregated values of the `feed_ids` or the `Error` of the processing.
+pub fn process_payload_for_feeds<T: RedStoneConfig>(
+    config: &T,
+    payload_bytes: impl Into<Bytes>,
+    feed_ids: &[FeedId],
+) -> Result<Vec<Value>, Error> {
+    let feed_indices = feed_ids
+        .iter()
+        .map(|&feed_id| config.config().require_feed_index(feed_id))
+        .collect::<Result<Vec<_>, _>>()?;
+    let bytes: Bytes = payload_bytes.into();
+    let payload = PayloadDecoder::<T::Environment, T::Crypto>::make_payload_for_feeds(
+        &mut bytes.into_vec(),
+        feed_ids,
+    )?;
+
+    T::Environment::print(|| format!("{:?}", payload));
+    log_event::<T::Environment>(
+        LogLevel::Info,
+        &LogEvent::PayloadDecoded {
+            package_count: payload.data_packages.len(),
+        },
+    );
+
+    let config = config.config();
+    let (_, values) = validate_and_aggregate::<T::Environment, _>(
+        config,
+        FixedClock::from(config).now(),
+        payload,
+        feed_ids,
+        |data_packages, config| aggregate_values_for_feeds(data_packages, config, &feed_indices),
+    )?;
+
+    Ok(values)
+}
+
+/// Variant of the [`process_payload`] returning also the decoded `Payload`,
+/// for the consumers storing the raw data packages, like the values of each of the signers.
+///
+/// The payload is decoded only once.
+///
+/// # Arguments
+///
+/// * `config` - Something that implements `RedStoneConfig`. Provides environment and crypto operations.
+/// * `payload_bytes` - Network-specific byte-list of the payload to be processed.
+///
+/// # Returns
+///
+/// * Returns the decoded `Payload` and the `ValidatedPayload` in case of successful payload processing,
+///   or the `Error` of the processing.
+pub fn process_payload_full<T: RedStoneConfig>(
+    config: &T,
+    payload_bytes: impl Into<Bytes>,
+) -> Result<(Payload, ValidatedPayload), Error> {
+    let payload = decode_payload(config, payload_bytes)?;
+    let validated_payload =
+        make_processor_result::<T::Environment>(config.config(), payload.clone())?;
+
+    Ok((payload, validated_payload))
+}
+
+/// Decodes the RedStone payload into its data packages, recovering their signers.
+///
+/// Neither validates nor aggregates the decoded values: the timestamps, signers and values are checked
+/// against the `Config` and aggregated in the separate step, by the [`process_decoded_payload`].
+/// The [`process_payload`] runs both of the steps.
+///
+/// # Arguments
+///
+/// * `config` - Something that implements `RedStoneConfig`. Provides environment and crypto operations.
+/// * `payload_bytes` - Network-specific byte-list of the payload to be decoded.
+///
+/// # Returns
+///
+/// * Returns the decoded `Payload` or the `Error` for the malformed payload.
+pub fn decode_payload<T: RedStoneConfig>(
+    _config: &T,
+    payload_bytes: impl Into<Bytes>,
+) -> Result<Payload, Error> {
+    #[cfg(feature = "tracing")]
+    let _span = tracing::debug_span!("decode_payload").entered();
+
+    let bytes: Bytes = payload_bytes.into();
+    let mut bytes = bytes.into_vec();
+    let payload = PayloadDecoder::<T::Environment, T::Crypto>::make_payload(&mut bytes)?;
+
+    T::Environment::print(|| format!("{:?}", payload));
+    log_event::<T::Environment>(
+        LogLevel::Info,
+        &LogEvent::PayloadDecoded {
+            package_count: payload.data_packages.len(),
+        },
+    );
+
+    Ok(payload)
+}
+
+/// Variant of the [`decode_payload`] recovering the signers of the data packages sequentially,
+/// as without the `rayon` feature, e.g. for comparing both in the benchmarks.
+///
+/// # Arguments
+///
+/// * `config` - Something that implements `RedStoneConfig`. Provides environment and crypto operations.
+/// * `payload_bytes` - Network-specific byte-list of the payload to be decoded.
+///
+/// # Returns
+///
+/// * Returns the decoded `Payload` or the `Error` for the malformed payload.
+#[cfg(feature = "rayon")]
+pub fn decode_payload_sequential<T: RedStoneConfig>(
+    _config: &T,
+    payload_bytes: impl Into<Bytes>,
+) -> Result<Payload, Error> {
+    let bytes: Bytes = payload_bytes.into();
+
+    PayloadDecoder::<T::Environment, T::Crypto>::make_payload_sequential(&mut bytes.into_vec())
+}
+
+/// Validates and aggregates the payload decoded by the [`decode_payload`], like the [`process_payload`] does.
+///
+/// Lets the payload be decoded once and aggregated with the different configs,
+/// e.g. with the different aggregation strategies.
+///
+/// # Arguments
+///
+/// * `config` - Something that implements `RedStoneConfig`. Provides environment and the config.
+/// * `payload` - The decoded payload.
+///
+/// # Returns
+///
+/// * Returns a `ProcessorResult` in case of successful payload processing.
+pub fn process_decoded_payload<T: RedStoneConfig>(config: &T, payload: Payload) -> ProcessorResult {
+    make_processor_result::<T::Environment>(config.config(), payload)
+}
+
+/// Processes the payload given as a hex string, with an optional `0x` prefix.
+///
+/// # Arguments
+///
+/// * `config` - Something that implements `RedStoneConfig`. Provides environment and crypto operations.
+/// * `payload_hex` - Hex representation of the payload bytes.
+///
+/// # Returns
+///
+/// * Returns a `ProcessorResult` in case of successful payload processing,
+///   or `Error::InvalidHex` when the `payload_hex` can't be decoded.
+#[cfg(feature = "helpers")]
+pub fn process_payload_hex(config: &impl RedStoneConfig, payload_hex: &str) -> ProcessorResult {
+    let payload_bytes = try_hex_to_bytes(payload_hex)?;
+
+    process_payload(config, payload_bytes)
+}
+
+/// The async variant of the [`process_payload`], recovering the signers with the given `AsyncCrypto`.
+///
+/// # Arguments
+///
+/// * `config` - Something that implements `RedStoneConfig`. Provides environment and the config;
+///   its crypto operations aren't used.
+/// * `crypto` - Async crypto operations used for the signer recovery, awaited per data package.
+/// * `payload_bytes` - Network-specific byte-list of the payload to be processed.
+///
+/// # Returns
+///
+/// * Returns a `ProcessorResult` in case of successful payload processing, or the `Error` of the processing.
+#[cfg(feature = "async-crypto")]
+pub async fn process_payload_async<T: RedStoneConfig>(
+    config: &T,
+    crypto: &impl AsyncCrypto,
+    payload_bytes: impl Into<Bytes>,
+) -> ProcessorResult {
+    let bytes: Bytes = payload_bytes.into();
+    let mut bytes = bytes.into_vec();
+    let payload =
+        PayloadDecoder::<T::Environment, _>::make_payload_async(crypto, &mut bytes).await?;
+
+    T::Environment::print(|| format!("{:?}", payload));
+    log_event::<T::Environment>(
+        LogLevel::Info,
+        &LogEvent::PayloadDecoded {
+            package_count: payload.data_packages.len(),
+        },
+    );
+
+    make_processor_result::<T::Environment>(config.config(), payload)
+}
+
 /// Internal trait, designed to extend `RedStoneConfig` implementations with ability to process payloads.
 trait RedStonePayloadProcessor {
-    /// Process given payload, panics in case of badly formed payload.
+    /// Process given payload.
     ///
     /// # Arguments
     /// * `payload_bytes` - Anything that can be transformed into `Bytes`
     ///
     /// # Returns
     ///
-    /// * Returns a `ProcessorResult` in case of successful payload processing. Will panic in case of bad input.
+    /// * Returns a `ProcessorResult` in case of successful payload processing, or the `Error` of the processing.
     fn process_payload(&self, payload_bytes: impl Into<Bytes>) -> ProcessorResult;
 }
 
 impl<T: RedStoneConfig> RedStonePayloadProcessor for T {
     fn process_payload(&self, payload_bytes: impl Into<Bytes>) -> ProcessorResult {
-        let mut bytes = payload_bytes.into();
-        let payload = PayloadDecoder::<T::Environment, T::Crypto>::make_payload(&mut bytes.0)?;
-
-        T::Environment::print(|| format!("{:?}", payload));
+        let payload = decode_payload(self, payload_bytes)?;
 
         make_processor_result::<T::Environment>(self.config(), payload)
     }
 }
 
 fn make_processor_result<Env: Environment>(config: &Config, payload: Payload) -> ProcessorResult {
-    let timestamp = payload.get_validated_timestamp(config)?;
+    make_processor_result_at::<Env>(config, FixedClock::from(config).now(), payload)
+}
+
+fn make_processor_result_at<Env: Environment>(
+    config: &Config,
+    block_timestamp: TimestampMillis,
+    payload: Payload,
+) -> ProcessorResult {
+    let signer_coverage = make_signer_coverage(config, &payload);
+    let (timestamp, values) = validate_and_aggregate::<Env, _>(
+        config,
+        block_timestamp,
+        payload,
+        config.feed_ids(),
+        aggregate_values,
+    )?;
 
-    let values = aggregate_values(payload.data_packages, config)?;
+    Ok(ValidatedPayload {
+        values,
+        feed_ids: config.feed_ids().clone(),
+        timestamp,
+        signer_coverage,
+    })
+}
 
-    Env::print(|| format!("{:?} {:?}", timestamp, values));
+fn make_detailed_processor_result<Env: Environment>(
+    config: &Config,
+    payload: Payload,
+) -> Result<DetailedPayload, Error> {
+    let signer_coverage = make_signer_coverage(config, &payload);
+    let (timestamp, details) = validate_and_aggregate::<Env, _>(
+        config,
+        FixedClock::from(config).now(),
+        payload,
+        config.feed_ids(),
+        aggregate_values_detailed,
+    )?;
+    let (values, spreads): (Vec<_>, Vec<_>) = details.into_iter().unzip();
 
-    Ok(ValidatedPayload { values, timestamp })
+    Ok(DetailedPayload {
+        validated_payload: ValidatedPayload {
+            values,
+            feed_ids: config.feed_ids().clone(),
+            timestamp,
+            signer_coverage,
+        },
+        spreads,
+    })
+}
+
+fn make_lenient_values<Env: Environment>(
+    config: &Config,
+    payload: Payload,
+) -> Result<Vec<Result<Value, Error>>, Error> {
+    let (_, values) = validate_and_aggregate::<Env, _>(
+        config,
+        FixedClock::from(config).now(),
+        payload,
+        config.feed_ids(),
+        |data_packages, config| Ok(aggregate_values_lenient(data_packages, config)),
+    )?;
+
+    Ok(values)
+}
+
+/// The step shared by all the processing variants: validates the payload timestamp against the `block_timestamp`
+/// and the data staleness, then aggregates the data packages with the given `aggregate` and logs the aggregated values
+/// of the `feed_ids`.
+fn validate_and_aggregate<Env: Environment, T: Aggregated>(
+    config: &Config,
+    block_timestamp: TimestampMillis,
+    payload: Payload,
+    feed_ids: &[FeedId],
+    aggregate: impl FnOnce(Vec<DataPackage>, &Config) -> Result<Vec<T>, Error>,
+) -> Result<(TimestampMillis, Vec<T>), Error> {
+    #[cfg(feature = "tracing")]
+    let _span = tracing::debug_span!("aggregate_values").entered();
+
+    let timestamp = payload.get_validated_timestamp(&AtBlockTimestamp {
+        config,
+        block_timestamp,
+    })?;
+
+    if let Some((time_now, data_ttl)) = config.staleness() {
+        verify_data_staleness(timestamp, *time_now, *data_ttl)?;
+    }
+
+    let aggregated = aggregate(payload.data_packages, config)?;
+
+    Env::print(|| format!("{:?} {:?}", timestamp, aggregated));
+    for (feed_id, aggregated) in feed_ids.iter().zip(aggregated.iter()) {
+        if let Some(value) = aggregated.value() {
+            log_event::<Env>(
+                LogLevel::Info,
+                &LogEvent::ValueAggregated {
+                    feed_id: *feed_id,
+                    value,
+                },
+            );
+        }
+    }
+
+    Ok((timestamp, aggregated))
+}
+
+/// The outcome of the aggregation of a single feed, of any of the processing variants.
+trait Aggregated: Debug {
+    /// Returns the aggregated value, if the feed has been aggregated successfully.
+    fn value(&self) -> Option<Value>;
+}
+
+impl Aggregated for Value {
+    fn value(&self) -> Option<Value> {
+        Some(*self)
+    }
+}
+
+/// The aggregated value with its spread.
+impl Aggregated for (Value, Value) {
+    fn value(&self) -> Option<Value> {
+        Some(self.0)
+    }
+}
+
+impl Aggregated for Result<Value, Error> {
+    fn value(&self) -> Option<Value> {
+        self.as_ref().ok().copied()
+    }
+}
+
+/// Counts the distinct configured signers that contributed a data point of each of the configured feeds.
+fn make_signer_coverage(config: &Config, payload: &Payload) -> Vec<(FeedId, u8)> {
+    config
+        .feed_ids()
+        .iter()
+        .map(|&feed_id| {
+            let signer_indices: BTreeSet<_> = payload
+                .data_packages
+                .iter()
+                .filter(|package| {
+                    package
+                        .data_points
+                        .iter()
+                        .any(|data_point| data_point.feed_id == feed_id)
+                })
+                .filter_map(|package| config.signer_index(&package.signer_address))
+                .collect();
+
+            (
+                feed_id,
+                u8::try_from(signer_indices.len()).unwrap_or(u8::MAX),
+            )
+        })
+        .collect()
 }
 
 #[cfg(feature = "helpers")]
@@ -70,10 +547,13 @@ mod tests {
     use crate::{
         core::{
             config::Config,
-            processor::make_processor_result,
-            processor_result::ValidatedPayload,
+            processor::{
+                make_detailed_processor_result, make_lenient_values, make_processor_result,
+            },
+            processor_result::{DetailedPayload, ProcessorResult, ValidatedPayload},
             test_helpers::{
-                BTC, ETH, TEST_BLOCK_TIMESTAMP, TEST_SIGNER_ADDRESS_1, TEST_SIGNER_ADDRESS_2,
+                from_ticker, AVAX, BTC, ETH, TEST_BLOCK_TIMESTAMP, TEST_SIGNER_ADDRESS_1,
+                TEST_SIGNER_ADDRESS_2, TEST_SIGNER_ADDRESS_3, TEST_SIGNER_ADDRESS_4,
             },
         },
         helpers::iter_into::IterInto,
@@ -119,11 +599,93 @@ mod tests {
             result,
             Ok(ValidatedPayload {
                 timestamp: (TEST_BLOCK_TIMESTAMP + 400).into(),
-                values: vec![12u8, 31].iter_into()
+                values: vec![12u8, 31].iter_into(),
+                feed_ids: vec![ETH, BTC].iter_into(),
+                signer_coverage: vec![(from_ticker(ETH), 2), (from_ticker(BTC), 2)]
+            })
+        );
+    }
+
+    #[test]
+    fn test_make_processor_result_for_fresh_payload() {
+        let result = make_processor_result_with_staleness(TEST_BLOCK_TIMESTAMP + 1000, 1000);
+
+        assert_eq!(
+            result,
+            Ok(ValidatedPayload {
+                timestamp: TEST_BLOCK_TIMESTAMP.into(),
+                values: vec![12u8].iter_into(),
+                feed_ids: vec![ETH].iter_into(),
+                signer_coverage: vec![(from_ticker(ETH), 2)]
             })
         );
     }
 
+    #[test]
+    fn test_make_processor_result_for_stale_payload() {
+        let result = make_processor_result_with_staleness(TEST_BLOCK_TIMESTAMP + 1001, 1000);
+
+        assert_eq!(
+            result,
+            Err(Error::DataStaleness(
+                TEST_BLOCK_TIMESTAMP.into(),
+                (TEST_BLOCK_TIMESTAMP + 1001).into()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_make_detailed_processor_result_and_lenient_values_for_stale_payload() {
+        let (config, payload) = staleness_config_and_payload(TEST_BLOCK_TIMESTAMP + 1001, 1000);
+        let expected = Error::DataStaleness(
+            TEST_BLOCK_TIMESTAMP.into(),
+            (TEST_BLOCK_TIMESTAMP + 1001).into(),
+        );
+
+        assert_eq!(
+            make_detailed_processor_result::<StdEnv>(&config, payload.clone()),
+            Err(expected.clone())
+        );
+        assert_eq!(
+            make_lenient_values::<StdEnv>(&config, payload),
+            Err(expected)
+        );
+    }
+
+    fn make_processor_result_with_staleness(time_now: u64, data_ttl: u64) -> ProcessorResult {
+        let (config, payload) = staleness_config_and_payload(time_now, data_ttl);
+
+        make_processor_result::<StdEnv>(&config, payload)
+    }
+
+    fn staleness_config_and_payload(time_now: u64, data_ttl: u64) -> (Config, Payload) {
+        let data_packages = vec![
+            DataPackage::test_single_data_point(
+                ETH,
+                11,
+                TEST_SIGNER_ADDRESS_1,
+                TEST_BLOCK_TIMESTAMP.into(),
+            ),
+            DataPackage::test_single_data_point(
+                ETH,
+                13,
+                TEST_SIGNER_ADDRESS_2,
+                TEST_BLOCK_TIMESTAMP.into(),
+            ),
+        ];
+        let config = Config::test(
+            None,
+            vec![TEST_SIGNER_ADDRESS_1, TEST_SIGNER_ADDRESS_2],
+            vec![ETH],
+            None,
+            None,
+            None,
+        )
+        .with_staleness(time_now.into(), data_ttl.into());
+
+        (config, Payload { data_packages })
+    }
+
     #[test]
     fn test_make_processor_result_for_multi_datapoint() {
         let data_packages = vec![
@@ -148,11 +710,106 @@ mod tests {
             result,
             Ok(ValidatedPayload {
                 timestamp: (TEST_BLOCK_TIMESTAMP + 5).into(),
-                values: vec![11u8, 31].iter_into()
+                values: vec![11u8, 31].iter_into(),
+                feed_ids: vec![ETH, BTC].iter_into(),
+                signer_coverage: vec![(from_ticker(ETH), 2), (from_ticker(BTC), 2)]
             })
         );
     }
 
+    #[test]
+    fn test_make_lenient_values_missing_feeds() {
+        let data_packages = vec![
+            DataPackage::test_multi_data_point(
+                vec![(ETH, 10), (BTC, 31), (AVAX, 51)],
+                TEST_SIGNER_ADDRESS_1,
+                TEST_BLOCK_TIMESTAMP.into(),
+            ),
+            DataPackage::test_multi_data_point(
+                vec![(ETH, 12), (BTC, 33)],
+                TEST_SIGNER_ADDRESS_2,
+                TEST_BLOCK_TIMESTAMP.into(),
+            ),
+        ];
+        let requested = [ETH, BTC, AVAX].map(from_ticker);
+
+        let config = Config::test(
+            None,
+            vec![TEST_SIGNER_ADDRESS_1, TEST_SIGNER_ADDRESS_2],
+            vec![ETH, BTC, AVAX],
+            None,
+            None,
+            None,
+        );
+        let values = make_lenient_values::<StdEnv>(
+            &config,
+            Payload {
+                data_packages: data_packages.clone(),
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            values[2],
+            Err(Error::InsufficientSignerCount(2, 1, from_ticker(AVAX)))
+        );
+        assert_eq!(
+            config.missing_feeds(&values, &requested),
+            vec![from_ticker(AVAX)]
+        );
+
+        let config = Config::test_with_signer_count_threshold_or_default(None);
+        let values = make_lenient_values::<StdEnv>(&config, Payload { data_packages }).unwrap();
+
+        assert_eq!(
+            config.missing_feeds(&values, &requested),
+            vec![from_ticker(AVAX)]
+        );
+        assert_eq!(config.missing_feeds(&values, &requested[..2]), Vec::new());
+    }
+
+    #[test]
+    fn test_make_processor_result_signer_coverage() {
+        let data_packages = vec![
+            DataPackage::test_multi_data_point(
+                vec![(ETH, 10), (BTC, 31)],
+                TEST_SIGNER_ADDRESS_1,
+                TEST_BLOCK_TIMESTAMP.into(),
+            ),
+            DataPackage::test_multi_data_point(
+                vec![(ETH, 12), (BTC, 33)],
+                TEST_SIGNER_ADDRESS_2,
+                TEST_BLOCK_TIMESTAMP.into(),
+            ),
+            DataPackage::test_single_data_point(
+                ETH,
+                14,
+                TEST_SIGNER_ADDRESS_3,
+                TEST_BLOCK_TIMESTAMP.into(),
+            ),
+        ];
+        let config = Config::test(
+            None,
+            vec![
+                TEST_SIGNER_ADDRESS_1,
+                TEST_SIGNER_ADDRESS_2,
+                TEST_SIGNER_ADDRESS_3,
+                TEST_SIGNER_ADDRESS_4,
+            ],
+            vec![ETH, BTC],
+            None,
+            None,
+            None,
+        );
+
+        let result = make_processor_result::<StdEnv>(&config, Payload { data_packages });
+
+        assert_eq!(
+            result.map(|validated_payload| validated_payload.signer_coverage),
+            Ok(vec![(from_ticker(ETH), 3), (from_ticker(BTC), 2)])
+        );
+    }
+
     #[test]
     fn test_make_processor_result_for_multi_datapoint_package_repetition() {
         let data_packages = vec![
@@ -216,4 +873,537 @@ mod tests {
             Err(Error::ReocuringFeedId(BTC.as_bytes().to_vec().into()))
         );
     }
+
+    #[test]
+    fn test_make_lenient_values_with_datapoint_repetition() {
+        let data_packages = vec![
+            DataPackage::test_multi_data_point(
+                vec![(ETH, 10), (BTC, 31), (BTC, 33)], // REPETITION IN DATAPOINTS HERE.
+                TEST_SIGNER_ADDRESS_2,
+                (TEST_BLOCK_TIMESTAMP).into(),
+            ),
+            DataPackage::test_multi_data_point(
+                vec![(ETH, 13), (BTC, 32)],
+                TEST_SIGNER_ADDRESS_1,
+                (TEST_BLOCK_TIMESTAMP).into(),
+            ),
+        ];
+
+        let result = make_lenient_values::<StdEnv>(
+            &Config::test_with_signer_count_threshold_or_default(None),
+            Payload { data_packages },
+        );
+
+        assert_eq!(
+            result,
+            Ok(vec![
+                Ok(11u8.into()),
+                Err(Error::ReocuringFeedId(from_ticker(BTC)))
+            ])
+        );
+    }
+
+    #[test]
+    fn test_make_lenient_values_for_too_old_payload() {
+        let data_packages = vec![DataPackage::test_single_data_point(
+            ETH,
+            11,
+            TEST_SIGNER_ADDRESS_1,
+            1000.into(),
+        )];
+
+        let result = make_lenient_values::<StdEnv>(
+            &Config::test_with_signer_count_threshold_or_default(None),
+            Payload { data_packages },
+        );
+
+        assert_eq!(result, Err(Error::TimestampTooOld(0, 1000.into())));
+    }
+
+    #[test]
+    fn test_make_detailed_processor_result_spreads() {
+        let data_packages = vec![
+            DataPackage::test_multi_data_point(
+                vec![(ETH, 100), (BTC, 31)],
+                TEST_SIGNER_ADDRESS_1,
+                TEST_BLOCK_TIMESTAMP.into(),
+            ),
+            DataPackage::test_single_data_point(
+                ETH,
+                110,
+                TEST_SIGNER_ADDRESS_2,
+                TEST_BLOCK_TIMESTAMP.into(),
+            ),
+            DataPackage::test_single_data_point(
+                ETH,
+                120,
+                TEST_SIGNER_ADDRESS_3,
+                TEST_BLOCK_TIMESTAMP.into(),
+            ),
+        ];
+        let config = Config::test(
+            Some(1),
+            vec![
+                TEST_SIGNER_ADDRESS_1,
+                TEST_SIGNER_ADDRESS_2,
+                TEST_SIGNER_ADDRESS_3,
+            ],
+            vec![ETH, BTC],
+            None,
+            None,
+            None,
+        );
+
+        let result = make_detailed_processor_result::<StdEnv>(&config, Payload { data_packages });
+
+        assert_eq!(
+            result,
+            Ok(DetailedPayload {
+                validated_payload: ValidatedPayload {
+                    timestamp: TEST_BLOCK_TIMESTAMP.into(),
+                    values: vec![110u8, 31].iter_into(),
+                    feed_ids: vec![ETH, BTC].iter_into(),
+                    signer_coverage: vec![(from_ticker(ETH), 3), (from_ticker(BTC), 1)]
+                },
+                spreads: vec![20u8, 0].iter_into(),
+            })
+        );
+    }
+
+    #[cfg(feature = "default-crypto")]
+    mod sample_payload {
+        use alloc::vec::Vec;
+
+        use primitive_types::U256;
+        #[cfg(target_arch = "wasm32")]
+        use wasm_bindgen_test::wasm_bindgen_test as test;
+
+        #[cfg(feature = "rayon")]
+        use crate::core::processor::decode_payload_sequential;
+        use crate::{
+            core::{
+                clock::FixedClock,
+                config::{Config, FeedMeta},
+                processor::{
+                    decode_payload, process_decoded_payload, process_payload, process_payload_at,
+                    process_payload_for_feeds, process_payload_full, process_payload_hex,
+                    process_payload_with_clock, process_payload_with_meta,
+                },
+                processor_result::ValidatedPayload,
+                test_helpers::{
+                    from_ticker, BTC, ETH, MAX_TIMESTAMP_AHEAD_MS, MAX_TIMESTAMP_DELAY_MS,
+                    SAMPLE_LARGE_VALUE_PAYLOAD_TIMESTAMP, SAMPLE_PAYLOAD_BTC_VALUE,
+                    SAMPLE_PAYLOAD_ETH_VALUE, SAMPLE_PAYLOAD_SIGNERS, SAMPLE_PAYLOAD_TIMESTAMP,
+                },
+            },
+            default_ext::StdRedStoneConfig,
+            helpers::{
+                hex::{sample_large_value_payload_bytes, sample_payload_bytes, sample_payload_hex},
+                iter_into::IterInto,
+            },
+            network::error::Error,
+            RedStoneConfig, Value,
+        };
+
+        #[test]
+        fn test_decode_payload() {
+            let config: StdRedStoneConfig = Config::test_sample_payload().into();
+
+            let payload = decode_payload(&config, sample_payload_bytes()).unwrap();
+
+            assert_eq!(payload.data_packages.len(), 15);
+            assert!(payload
+                .data_packages
+                .iter()
+                .all(|package| package.timestamp == SAMPLE_PAYLOAD_TIMESTAMP.into()));
+        }
+
+        #[cfg(feature = "rayon")]
+        #[test]
+        fn test_decode_payload_sequential() {
+            let config: StdRedStoneConfig = Config::test_sample_payload().into();
+
+            assert_eq!(
+                decode_payload_sequential(&config, sample_payload_bytes()),
+                decode_payload(&config, sample_payload_bytes())
+            );
+        }
+
+        #[test]
+        fn test_process_payload_for_feeds() {
+            let config: StdRedStoneConfig = Config::test_sample_payload().into();
+
+            assert_eq!(
+                process_payload_for_feeds(
+                    &config,
+                    sample_payload_bytes(),
+                    &[from_ticker(BTC), from_ticker(ETH)]
+                ),
+                Ok(vec![
+                    SAMPLE_PAYLOAD_BTC_VALUE.into(),
+                    SAMPLE_PAYLOAD_ETH_VALUE.into()
+                ])
+            );
+            assert_eq!(
+                process_payload_for_feeds(&config, sample_payload_bytes(), &[from_ticker("AVAX")]),
+                Err(Error::ConfigInvalidFeedId(from_ticker("AVAX")))
+            );
+        }
+
+        #[test]
+        fn test_process_payload_for_feeds_skipping_other_config_feeds() {
+            let config: StdRedStoneConfig = Config::test(
+                None,
+                SAMPLE_PAYLOAD_SIGNERS.to_vec(),
+                vec![ETH, BTC, "XYZ"],
+                Some(SAMPLE_PAYLOAD_TIMESTAMP.into()),
+                None,
+                None,
+            )
+            .into();
+
+            assert_eq!(
+                process_payload(&config, sample_payload_bytes()),
+                Err(Error::InsufficientSignerCount(2, 0, from_ticker("XYZ")))
+            );
+            assert_eq!(
+                process_payload_for_feeds(&config, sample_payload_bytes(), &[from_ticker(ETH)]),
+                Ok(vec![SAMPLE_PAYLOAD_ETH_VALUE.into()])
+            );
+        }
+
+        #[test]
+        fn test_process_decoded_payload() {
+            let config: StdRedStoneConfig = Config::test_sample_payload().into();
+            let payload = decode_payload(&config, sample_payload_bytes()).unwrap();
+
+            assert_eq!(
+                process_decoded_payload(&config, payload),
+                process_payload(&config, sample_payload_bytes())
+            );
+        }
+
+        #[test]
+        fn test_process_payload_full() {
+            let config: StdRedStoneConfig = Config::test_sample_payload().into();
+
+            let (payload, validated_payload) =
+                process_payload_full(&config, sample_payload_bytes()).unwrap();
+
+            assert_eq!(payload.data_packages.len(), 15);
+            assert_eq!(
+                payload,
+                decode_payload(&config, sample_payload_bytes()).unwrap()
+            );
+            assert_eq!(
+                Ok(validated_payload),
+                process_payload(&config, sample_payload_bytes())
+            );
+        }
+
+        #[test]
+        fn test_process_payload_with_meta() {
+            let feed_meta = FeedMeta {
+                decimals: 8,
+                description: "ETH/USD".into(),
+            };
+            let config: StdRedStoneConfig = Config::test_sample_payload()
+                .with_feed_meta(from_ticker(ETH), feed_meta.clone())
+                .into();
+
+            let result = process_payload_with_meta(&config, sample_payload_bytes()).unwrap();
+
+            assert_eq!(result.feed_meta, vec![Some(feed_meta), None]);
+            assert_eq!(
+                result.feed_meta[0].as_ref().map(|meta| meta.decimals),
+                Some(8)
+            );
+            assert_eq!(
+                Ok(result.validated_payload),
+                process_payload(&config, sample_payload_bytes())
+            );
+        }
+
+        #[cfg(feature = "std")]
+        #[test]
+        fn test_process_payload_logs_events() {
+            use std::sync::Mutex;
+
+            use crate::{
+                default_ext::DefaultCrypto,
+                network::{Environment, LogEvent, LogLevel},
+                RedStoneConfigImpl,
+            };
+
+            static EVENTS: Mutex<Vec<LogEvent>> = Mutex::new(Vec::new());
+
+            struct RecordingEnv;
+
+            impl Environment for RecordingEnv {
+                fn print<F: FnOnce() -> String>(_print_content: F) {}
+
+                fn log(_level: LogLevel, event: &LogEvent) {
+                    EVENTS.lock().unwrap().push(event.clone());
+                }
+            }
+
+            let config: RedStoneConfigImpl<DefaultCrypto, RecordingEnv> =
+                Config::test_sample_payload().into();
+
+            process_payload(&config, sample_payload_bytes()).unwrap();
+
+            let events = EVENTS.lock().unwrap();
+            let recovered_signers = events
+                .iter()
+                .filter(|event| matches!(event, LogEvent::SignerRecovered { .. }))
+                .count();
+
+            assert_eq!(recovered_signers, 15);
+            assert_eq!(
+                events[recovered_signers..],
+                [
+                    LogEvent::PayloadDecoded { package_count: 15 },
+                    LogEvent::ValueAggregated {
+                        feed_id: from_ticker(ETH),
+                        value: SAMPLE_PAYLOAD_ETH_VALUE.into()
+                    },
+                    LogEvent::ValueAggregated {
+                        feed_id: from_ticker(BTC),
+                        value: SAMPLE_PAYLOAD_BTC_VALUE.into()
+                    },
+                ]
+            );
+        }
+
+        #[cfg(feature = "tracing")]
+        #[tracing_test::traced_test]
+        #[test]
+        fn test_process_payload_traces_events() {
+            let config: StdRedStoneConfig = Config::test_sample_payload().into();
+
+            process_payload(&config, sample_payload_bytes()).unwrap();
+
+            assert!(logs_contain("decode_payload"));
+            assert!(logs_contain("payload decoded package_count=15"));
+            assert!(logs_contain("signer recovered signer="));
+            assert!(logs_contain(&format!(
+                "value aggregated feed_id=ETH value={}",
+                SAMPLE_PAYLOAD_ETH_VALUE
+            )));
+            assert!(logs_contain(&format!(
+                "value aggregated feed_id=BTC value={}",
+                SAMPLE_PAYLOAD_BTC_VALUE
+            )));
+        }
+
+        #[test]
+        fn test_process_payload_hex() {
+            let config: StdRedStoneConfig = Config::test_sample_payload().into();
+            let payload_hex = sample_payload_hex();
+
+            for hex in [payload_hex.clone(), format!("0x{}", payload_hex)] {
+                assert_eq!(
+                    process_payload_hex(&config, &hex),
+                    Ok(ValidatedPayload {
+                        timestamp: SAMPLE_PAYLOAD_TIMESTAMP.into(),
+                        values: vec![SAMPLE_PAYLOAD_ETH_VALUE, SAMPLE_PAYLOAD_BTC_VALUE]
+                            .iter_into(),
+                        feed_ids: vec![ETH, BTC].iter_into(),
+                        signer_coverage: vec![(from_ticker(ETH), 5), (from_ticker(BTC), 5)]
+                    })
+                );
+            }
+        }
+
+        #[test]
+        fn test_process_payload_large_value() {
+            let config: StdRedStoneConfig = Config::test_sample_large_value_payload().into();
+
+            let result = process_payload(&config, sample_large_value_payload_bytes()).unwrap();
+
+            assert_eq!(
+                result.values,
+                vec![Value::from_u256(U256::from(u128::MAX) + 123456790)]
+            );
+            assert!(result.values[0].to_u256() > U256::from(u128::MAX));
+            assert_eq!(
+                result.timestamp,
+                SAMPLE_LARGE_VALUE_PAYLOAD_TIMESTAMP.into()
+            );
+        }
+
+        #[test]
+        fn test_process_payload_at_block_timestamps() {
+            let config: StdRedStoneConfig = Config::test_sample_payload().into();
+            let expected_values: Vec<Value> =
+                vec![SAMPLE_PAYLOAD_ETH_VALUE, SAMPLE_PAYLOAD_BTC_VALUE].iter_into();
+
+            for block_timestamp in [
+                SAMPLE_PAYLOAD_TIMESTAMP + 60000,
+                SAMPLE_PAYLOAD_TIMESTAMP - 60000,
+            ] {
+                let result =
+                    process_payload_at(&config, sample_payload_bytes(), block_timestamp.into());
+
+                assert_eq!(result.unwrap().values, expected_values);
+            }
+            assert_eq!(
+                *config.config().block_timestamp(),
+                SAMPLE_PAYLOAD_TIMESTAMP.into()
+            );
+        }
+
+        #[test]
+        fn test_process_payload_at_invalid_block_timestamps() {
+            let config: StdRedStoneConfig = Config::test_sample_payload().into();
+            let timestamp = SAMPLE_PAYLOAD_TIMESTAMP.into();
+
+            assert_eq!(
+                process_payload_at(
+                    &config,
+                    sample_payload_bytes(),
+                    (SAMPLE_PAYLOAD_TIMESTAMP + MAX_TIMESTAMP_DELAY_MS + 1).into()
+                ),
+                Err(Error::TimestampTooOld(0, timestamp))
+            );
+            assert_eq!(
+                process_payload_at(
+                    &config,
+                    sample_payload_bytes(),
+                    (SAMPLE_PAYLOAD_TIMESTAMP - MAX_TIMESTAMP_AHEAD_MS - 1).into()
+                ),
+                Err(Error::TimestampTooFuture(0, timestamp))
+            );
+            assert!(process_payload(&config, sample_payload_bytes()).is_ok());
+        }
+
+        #[test]
+        fn test_process_payload_with_fixed_clock() {
+            let config: StdRedStoneConfig = Config::test_sample_payload().into();
+            let timestamp = SAMPLE_PAYLOAD_TIMESTAMP.into();
+
+            assert_eq!(
+                process_payload_with_clock(
+                    &config,
+                    sample_payload_bytes(),
+                    &FixedClock((SAMPLE_PAYLOAD_TIMESTAMP + MAX_TIMESTAMP_DELAY_MS).into())
+                )
+                .map(|result| result.timestamp),
+                Ok(timestamp)
+            );
+            assert_eq!(
+                process_payload_with_clock(
+                    &config,
+                    sample_payload_bytes(),
+                    &FixedClock((SAMPLE_PAYLOAD_TIMESTAMP + MAX_TIMESTAMP_DELAY_MS + 1).into())
+                ),
+                Err(Error::TimestampTooOld(0, timestamp))
+            );
+            assert_eq!(
+                process_payload_with_clock(
+                    &config,
+                    sample_payload_bytes(),
+                    &FixedClock::from(config.config())
+                ),
+                process_payload(&config, sample_payload_bytes())
+            );
+        }
+
+        #[cfg(feature = "std")]
+        #[cfg(not(target_arch = "wasm32"))]
+        #[test]
+        fn test_process_payload_with_system_clock() {
+            use crate::core::clock::SystemClock;
+
+            let config: StdRedStoneConfig = Config::test_sample_payload().into();
+
+            assert_eq!(
+                process_payload_with_clock(&config, sample_payload_bytes(), &SystemClock),
+                Err(Error::TimestampTooOld(0, SAMPLE_PAYLOAD_TIMESTAMP.into()))
+            );
+        }
+
+        #[test]
+        fn test_process_payload_hex_invalid() {
+            let config: StdRedStoneConfig = Config::test_sample_payload().into();
+
+            assert_eq!(
+                process_payload_hex(&config, &(sample_payload_hex() + "0")),
+                Err(Error::InvalidHex(sample_payload_hex() + "0"))
+            );
+            assert_eq!(
+                process_payload_hex(&config, "0x12zz"),
+                Err(Error::InvalidHex("zz".into()))
+            );
+        }
+    }
+
+    #[cfg(feature = "async-crypto")]
+    #[cfg(feature = "default-crypto")]
+    mod async_crypto {
+        use core::future::Future;
+
+        use futures::executor::block_on;
+
+        use crate::{
+            core::{
+                config::Config,
+                processor::{process_payload, process_payload_async},
+                processor_result::ValidatedPayload,
+                test_helpers::{
+                    from_ticker, BTC, ETH, SAMPLE_PAYLOAD_BTC_VALUE, SAMPLE_PAYLOAD_ETH_VALUE,
+                    SAMPLE_PAYLOAD_TIMESTAMP,
+                },
+            },
+            default_ext::{DefaultCrypto, StdRedStoneConfig},
+            helpers::{hex::sample_payload_bytes, iter_into::IterInto},
+            AsyncCrypto, Crypto, CryptoError, SignerAddress,
+        };
+
+        struct AsyncDefaultCrypto;
+
+        impl AsyncCrypto for AsyncDefaultCrypto {
+            fn recover_address(
+                &self,
+                message: &[u8],
+                signature: &[u8],
+            ) -> impl Future<Output = Result<SignerAddress, CryptoError>> {
+                async move { DefaultCrypto::recover_address(message, signature) }
+            }
+        }
+
+        #[test]
+        fn test_process_payload_async() {
+            let config: StdRedStoneConfig = Config::test_sample_payload().into();
+
+            let result = block_on(process_payload_async(
+                &config,
+                &AsyncDefaultCrypto,
+                sample_payload_bytes(),
+            ));
+
+            assert_eq!(
+                result,
+                Ok(ValidatedPayload {
+                    timestamp: SAMPLE_PAYLOAD_TIMESTAMP.into(),
+                    values: vec![SAMPLE_PAYLOAD_ETH_VALUE, SAMPLE_PAYLOAD_BTC_VALUE].iter_into(),
+                    feed_ids: vec![ETH, BTC].iter_into(),
+                    signer_coverage: vec![(from_ticker(ETH), 5), (from_ticker(BTC), 5)]
+                })
+            );
+        }
+
+        #[test]
+        fn test_process_payload_async_with_sync_crypto() {
+            let config: StdRedStoneConfig = Config::test_sample_payload().into();
+
+            let result = block_on(process_payload_async(
+                &config,
+                &DefaultCrypto,
+                sample_payload_bytes(),
+            ));
+
+            assert_eq!(result, process_payload(&config, sample_payload_bytes()));
+        }
+    }
 }
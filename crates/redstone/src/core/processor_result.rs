@@ -1,6 +1,7 @@
 use alloc::vec::Vec;
+use core::fmt::{Display, Formatter};
 
-use crate::{network::error::Error, types::Value, TimestampMillis};
+use crate::{core::config::FeedMeta, network::error::Error, types::Value, FeedId, TimestampMillis};
 
 pub type ProcessorResult = Result<ValidatedPayload, Error>;
 
@@ -9,6 +10,7 @@ pub type ProcessorResult = Result<ValidatedPayload, Error>;
 /// This structure is used to encapsulate the outcome of a RedStone payload processing operation,
 /// particularly focusing on time-sensitive data and its associated values, according to the `Config`.
 #[derive(Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ValidatedPayload {
     /// The timestamp encountered during processing.
     ///
@@ -19,8 +21,102 @@ pub struct ValidatedPayload {
     /// A collection of values processed during the operation.
     ///
     /// Each element in this vector represents a processed value corresponding
-    /// to the passed data_feed item in the `Config`.
+    /// to the item of the `feed_ids`.
     pub values: Vec<Value>,
+
+    /// The feed id of each of the `values`.
+    ///
+    /// Equal to the `feed_ids` of the `Config` for the results of the processing,
+    /// see the [`ValidatedPayload::merge`] for the merged ones.
+    pub feed_ids: Vec<FeedId>,
+
+    /// The number of distinct signers that contributed to each feed, for monitoring the quorum health.
+    ///
+    /// Each element corresponds to the passed data_feed item in the `Config`.
+    /// Only the signers from the `Config` are counted.
+    pub signer_coverage: Vec<(FeedId, u8)>,
+}
+
+/// Represents the result of processing the RedStone payload along with the spread of each of the feeds.
+#[derive(Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct DetailedPayload {
+    /// The result of processing the payload, like returned by the `process_payload`.
+    pub validated_payload: ValidatedPayload,
+
+    /// The difference of the maximum and the minimum of the values contributing to each feed.
+    ///
+    /// Each element corresponds to the passed data_feed item in the `Config`.
+    pub spreads: Vec<Value>,
+}
+
+/// Represents the result of processing the RedStone payload along with the metadata of each of the feeds.
+#[derive(Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct DescribedPayload {
+    /// The result of processing the payload, like returned by the `process_payload`.
+    pub validated_payload: ValidatedPayload,
+
+    /// The metadata of each of the feeds, `None` for the feeds without the metadata in the `Config`.
+    ///
+    /// Each element corresponds to the passed data_feed item in the `Config`.
+    pub feed_meta: Vec<Option<FeedMeta>>,
+}
+
+impl ValidatedPayload {
+    /// Merges the payloads, keeping per feed the value of the payload with the greater timestamp.
+    ///
+    /// The feeds are matched by the `feed_ids`: the feeds of the newer payload come first,
+    /// followed by the feeds present only in the older one, the `self` is preferred for equal timestamps.
+    /// So the merged `feed_ids` aren't the ones of any `Config`, the `values` still correspond to them.
+    /// The `signer_coverage` is kept for the feeds having it in their payload,
+    /// the payloads built without the coverage don't add any.
+    /// The timestamp of the merged payload is the older one, as it may contain the values of both.
+    pub fn merge(self, other: ValidatedPayload) -> ValidatedPayload {
+        let (newer, older) = if other.timestamp > self.timestamp {
+            (other, self)
+        } else {
+            (self, other)
+        };
+        let mut values = newer.values;
+        let mut feed_ids = newer.feed_ids;
+        let mut signer_coverage = newer.signer_coverage;
+
+        for (feed_id, value) in older.feed_ids.into_iter().zip(older.values) {
+            if feed_ids.contains(&feed_id) {
+                continue;
+            }
+            feed_ids.push(feed_id);
+            values.push(value);
+            signer_coverage.extend(
+                older
+                    .signer_coverage
+                    .iter()
+                    .find(|(covered_feed_id, _)| *covered_feed_id == feed_id),
+            );
+        }
+
+        ValidatedPayload {
+            timestamp: older.timestamp,
+            values,
+            feed_ids,
+            signer_coverage,
+        }
+    }
+}
+
+/// Summarizes the payload as the timestamp followed by the decimal values of the feeds,
+/// like `timestamp=1707307760000 ETH=236389750361 BTC=4407250000000`.
+impl Display for ValidatedPayload {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "timestamp={:?}", self.timestamp)?;
+
+        for (feed_id, value) in self.feed_ids.iter().zip(self.values.iter()) {
+            write!(f, " {}={}", feed_id, value.to_u256())?;
+        }
+
+        Ok(())
+    }
 }
 
 impl From<ValidatedPayload> for (TimestampMillis, Vec<Value>) {
@@ -28,3 +124,124 @@ impl From<ValidatedPayload> for (TimestampMillis, Vec<Value>) {
         (validated_payload.timestamp, validated_payload.values)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    use crate::{core::processor_result::ValidatedPayload, FeedId, Value};
+
+    #[test]
+    fn test_validated_payload_display() {
+        let payload = ValidatedPayload {
+            timestamp: 1707307760000.into(),
+            values: vec![Value::from(159504422175u128), Value::from(0u8)],
+            feed_ids: vec![FeedId::from(b"ETH".to_vec()), FeedId::from(b"BTC".to_vec())],
+            signer_coverage: vec![
+                (FeedId::from(b"ETH".to_vec()), 5),
+                (FeedId::from(b"BTC".to_vec()), 3),
+            ],
+        };
+
+        assert_eq!(
+            payload.to_string(),
+            "timestamp=1707307760000 ETH=159504422175 BTC=0"
+        );
+    }
+
+    #[test]
+    fn test_validated_payload_display_without_signer_coverage() {
+        let payload = ValidatedPayload {
+            timestamp: 1707307760000.into(),
+            values: vec![Value::from(159504422175u128), Value::from(0u8)],
+            feed_ids: vec![FeedId::from(b"ETH".to_vec()), FeedId::from(b"BTC".to_vec())],
+            signer_coverage: vec![],
+        };
+
+        assert_eq!(
+            payload.to_string(),
+            "timestamp=1707307760000 ETH=159504422175 BTC=0"
+        );
+    }
+
+    #[test]
+    fn test_validated_payload_merge() {
+        let eth = FeedId::from(b"ETH".to_vec());
+        let btc = FeedId::from(b"BTC".to_vec());
+        let avax = FeedId::from(b"AVAX".to_vec());
+        let newer = || ValidatedPayload {
+            timestamp: 2000.into(),
+            values: vec![Value::from(21u8), Value::from(23u8)],
+            feed_ids: vec![eth, avax],
+            signer_coverage: vec![(eth, 3), (avax, 2)],
+        };
+        let older = || ValidatedPayload {
+            timestamp: 1000.into(),
+            values: vec![Value::from(12u8), Value::from(11u8)],
+            feed_ids: vec![btc, eth],
+            signer_coverage: vec![(btc, 4), (eth, 5)],
+        };
+        let expected = ValidatedPayload {
+            timestamp: 1000.into(),
+            values: vec![Value::from(21u8), Value::from(23u8), Value::from(12u8)],
+            feed_ids: vec![eth, avax, btc],
+            signer_coverage: vec![(eth, 3), (avax, 2), (btc, 4)],
+        };
+
+        assert_eq!(older().merge(newer()), expected);
+        assert_eq!(newer().merge(older()), expected);
+    }
+
+    #[test]
+    fn test_validated_payload_merge_without_signer_coverage() {
+        let eth = FeedId::from(b"ETH".to_vec());
+        let btc = FeedId::from(b"BTC".to_vec());
+        let newer = ValidatedPayload {
+            timestamp: 2000.into(),
+            values: vec![Value::from(21u8)],
+            feed_ids: vec![eth],
+            signer_coverage: vec![(eth, 3)],
+        };
+        let older = ValidatedPayload {
+            timestamp: 1000.into(),
+            values: vec![Value::from(12u8), Value::from(11u8)],
+            feed_ids: vec![btc, eth],
+            signer_coverage: vec![],
+        };
+
+        assert_eq!(
+            older.merge(newer),
+            ValidatedPayload {
+                timestamp: 1000.into(),
+                values: vec![Value::from(21u8), Value::from(12u8)],
+                feed_ids: vec![eth, btc],
+                signer_coverage: vec![(eth, 3)],
+            }
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_validated_payload_to_json() {
+        let payload = ValidatedPayload {
+            timestamp: 1707307760000.into(),
+            values: vec![
+                Value::from(236389750361u128),
+                Value::from_u256(primitive_types::U256::MAX),
+            ],
+            feed_ids: vec![FeedId::from(b"ETH".to_vec()), FeedId::from(b"BTC".to_vec())],
+            signer_coverage: vec![
+                (FeedId::from(b"ETH".to_vec()), 5),
+                (FeedId::from(b"BTC".to_vec()), 3),
+            ],
+        };
+
+        assert_eq!(
+            serde_json::to_string(&payload).unwrap(),
+            r#"{"timestamp":1707307760000,"values":["236389750361","115792089237316195423570985008687907853269984665640564039457584007913129639935"],"feed_ids":["ETH","BTC"],"signer_coverage":[["ETH",5],["BTC",3]]}"#
+        );
+    }
+}
@@ -2,12 +2,9 @@ use alloc::vec::Vec;
 
 use crate::{
     core::config::Config,
-    helpers::{
-        hex::{hex_to_bytes, make_feed_id},
-        iter_into::IterInto,
-    },
+    helpers::{hex::hex_to_bytes, iter_into::IterInto},
     protocol::{data_package::DataPackage, data_point::DataPoint},
-    TimestampMillis,
+    FeedId, TimestampMillis,
 };
 
 pub(crate) const TEST_BLOCK_TIMESTAMP: u64 = 2000000000000;
@@ -19,10 +16,33 @@ pub(crate) const TEST_SIGNER_ADDRESS_2: &str = "109b4a318a4f5ddcbca6349b45f881b4
 pub(crate) const TEST_SIGNER_ADDRESS_3: &str = "01c9827101a57ac9e9fb7034510e19afcf9c0207";
 pub(crate) const TEST_SIGNER_ADDRESS_4: &str = "264dee744b727613cb76e0cb2f97cd6eda95b39e";
 
+pub(crate) const SAMPLE_PAYLOAD_TIMESTAMP: u64 = 1707307760000;
+pub(crate) const SAMPLE_PAYLOAD_SIGNERS: [&str; 5] = [
+    "83cba8c619fb629b81a65c2e67fe15cf3e3c9747",
+    "2c59617248994d12816ee1fa77ce0a64eeb456bf",
+    "1ea62d73edf8ac05dfcea1a34b9796e937a29eff",
+    "12470f7aba85c8b81d63137dd5925d6ee114952b",
+    "109b4a318a4f5ddcbca6349b45f881b4137deafb",
+];
+pub(crate) const SAMPLE_PAYLOAD_ETH_VALUE: u128 = 236389750361;
+pub(crate) const SAMPLE_PAYLOAD_BTC_VALUE: u128 = 4291501662498;
+
+pub(crate) const SAMPLE_LARGE_VALUE_PAYLOAD_TIMESTAMP: u64 = 1725975800000;
+pub(crate) const SAMPLE_LARGE_VALUE_PAYLOAD_SIGNERS: [&str; 2] = [
+    "f39fd6e51aad88f6f4ce6ab8827279cfffb92266",
+    "70997970c51812dc3a010c7d01b50e0d17dc79c8",
+];
+pub(crate) const LARGE: &str = "LARGE";
+
 pub(crate) const ETH: &str = "ETH";
 pub(crate) const BTC: &str = "BTC";
 pub(crate) const AVAX: &str = "AVAX";
 
+/// The [`FeedId`] of the ticker, panicking for the one longer than the feed id.
+pub(crate) fn from_ticker(ticker: &str) -> FeedId {
+    FeedId::from_ticker(ticker).unwrap()
+}
+
 impl Config {
     /// Creates config with default signer_count_threshold equal 2 if not specified otherwise.
     ///
@@ -78,6 +98,34 @@ impl Config {
         )
     }
 
+    /// Creates config matching the `sample-data/payload.hex`.
+    ///
+    /// It uses all 5 signers of the sample payload, ETH and BTC feed_ids and the sample payload timestamp.
+    pub(crate) fn test_sample_payload() -> Self {
+        Self::test(
+            None,
+            SAMPLE_PAYLOAD_SIGNERS.to_vec(),
+            vec![ETH, BTC],
+            Some(SAMPLE_PAYLOAD_TIMESTAMP.into()),
+            None,
+            None,
+        )
+    }
+
+    /// Creates config for the sample payload with a value exceeding the `u128`.
+    ///
+    /// It uses both signers of the sample payload, the LARGE feed_id and the sample payload timestamp.
+    pub(crate) fn test_sample_large_value_payload() -> Self {
+        Self::test(
+            None,
+            SAMPLE_LARGE_VALUE_PAYLOAD_SIGNERS.to_vec(),
+            vec![LARGE],
+            Some(SAMPLE_LARGE_VALUE_PAYLOAD_TIMESTAMP.into()),
+            None,
+            None,
+        )
+    }
+
     /// Creates config with default signer_count_threshold equal 2 if not specified otherwise, and feed_ids.
     pub(crate) fn test(
         signer_count_threshold: Option<u8>,
@@ -109,8 +157,12 @@ impl DataPackage {
         DataPackage {
             signer_address: hex_to_bytes(signer_address.into()).into(),
             timestamp: timestamp.unwrap_or(TEST_BLOCK_TIMESTAMP).into(),
+            #[cfg(feature = "signatures")]
+            signature: Default::default(),
+            #[cfg(feature = "signatures")]
+            signable_bytes: Default::default(),
             data_points: vec![DataPoint {
-                feed_id: make_feed_id(feed_id),
+                feed_id: from_ticker(feed_id),
                 value: value.into(),
             }],
         }
@@ -124,10 +176,14 @@ impl DataPackage {
         DataPackage {
             signer_address: hex_to_bytes(signer_address.into()).into(),
             timestamp: timestamp.unwrap_or(TEST_BLOCK_TIMESTAMP).into(),
+            #[cfg(feature = "signatures")]
+            signature: Default::default(),
+            #[cfg(feature = "signatures")]
+            signable_bytes: Default::default(),
             data_points: data_points
                 .into_iter()
                 .map(|(feed_id, value)| DataPoint {
-                    feed_id: make_feed_id(feed_id),
+                    feed_id: from_ticker(feed_id),
                     value: value.into(),
                 })
                 .collect(),
@@ -1,12 +1,8 @@
 use alloc::vec::Vec;
 
 use crate::{
-    core::config::Config,
-    network::error::Error,
-    protocol::constants::{MAX_TIMESTAMP_AHEAD_MS, MAX_TIMESTAMP_DELAY_MS},
-    types::Value,
-    utils::filter::FilterSome,
-    FeedId, SignerAddress, TimestampMillis,
+    core::config::Config, network::error::Error, types::Value, utils::filter::FilterSome, FeedId,
+    SignerAddress, TimestampMillis,
 };
 /// A trait defining validation operations for data feeds and signers.
 ///
@@ -35,6 +31,7 @@ pub trait Validator {
     /// This method accepts a signer identifier and returns an
     /// `Option<usize>` indicating the signer's index within a collection of signers. If the signer
     /// is not found, `None` is returned.
+    /// Signers are matched by their canonical bytes, so the hex case they were parsed from doesn't matter.
     ///
     /// # Arguments
     ///
@@ -89,12 +86,12 @@ pub trait Validator {
 impl Validator for Config {
     #[inline]
     fn feed_index(&self, feed_id: FeedId) -> Option<usize> {
-        self.feed_ids().iter().position(|&elt| elt == feed_id)
+        self.cached_feed_index(&feed_id)
     }
 
     #[inline]
     fn signer_index(&self, signer: &SignerAddress) -> Option<usize> {
-        self.signers().iter().position(|elt| elt == signer)
+        self.cached_signer_index(signer)
     }
 
     #[inline]
@@ -121,24 +118,71 @@ impl Validator for Config {
         index: usize,
         timestamp: TimestampMillis,
     ) -> Result<TimestampMillis, Error> {
-        if !timestamp
-            .add(MAX_TIMESTAMP_DELAY_MS)
-            .is_same_or_after(*self.block_timestamp())
-        {
-            return Err(Error::TimestampTooOld(index, timestamp));
-        }
-        if !timestamp.is_same_or_before(self.block_timestamp().add(MAX_TIMESTAMP_AHEAD_MS)) {
-            return Err(Error::TimestampTooFuture(index, timestamp));
-        }
+        check_timestamp_in_range(index, timestamp, self.valid_timestamp_range())
+    }
+}
+
+/// The `Config` validating the timestamps against the given `block_timestamp` instead of the configured one.
+pub(crate) struct AtBlockTimestamp<'a> {
+    pub(crate) config: &'a Config,
+    pub(crate) block_timestamp: TimestampMillis,
+}
+
+impl Validator for AtBlockTimestamp<'_> {
+    #[inline]
+    fn feed_index(&self, feed_id: FeedId) -> Option<usize> {
+        self.config.feed_index(feed_id)
+    }
 
-        Ok(timestamp)
+    #[inline]
+    fn signer_index(&self, signer: &SignerAddress) -> Option<usize> {
+        self.config.signer_index(signer)
+    }
+
+    #[inline]
+    fn validate_signer_count_threshold(
+        &self,
+        index: usize,
+        values: &[Option<Value>],
+    ) -> Result<Vec<Value>, Error> {
+        self.config.validate_signer_count_threshold(index, values)
+    }
+
+    #[inline]
+    fn validate_timestamp(
+        &self,
+        index: usize,
+        timestamp: TimestampMillis,
+    ) -> Result<TimestampMillis, Error> {
+        check_timestamp_in_range(
+            index,
+            timestamp,
+            self.config.valid_timestamp_range_at(self.block_timestamp),
+        )
     }
 }
 
+#[inline]
+fn check_timestamp_in_range(
+    index: usize,
+    timestamp: TimestampMillis,
+    (min_timestamp, max_timestamp): (TimestampMillis, TimestampMillis),
+) -> Result<TimestampMillis, Error> {
+    if !timestamp.is_same_or_after(min_timestamp) {
+        return Err(Error::TimestampTooOld(index, timestamp));
+    }
+    if !timestamp.is_same_or_before(max_timestamp) {
+        return Err(Error::TimestampTooFuture(index, timestamp));
+    }
+
+    Ok(timestamp)
+}
+
 #[cfg(feature = "helpers")]
 #[cfg(test)]
 mod tests {
-    use alloc::vec::Vec;
+    use alloc::{format, vec::Vec};
+    use core::str::FromStr;
 
     use itertools::Itertools;
     #[cfg(target_arch = "wasm32")]
@@ -148,34 +192,34 @@ mod tests {
         core::{
             config::Config,
             test_helpers::{
-                AVAX, BTC, ETH, TEST_BLOCK_TIMESTAMP, TEST_SIGNER_ADDRESS_1, TEST_SIGNER_ADDRESS_2,
-                TEST_SIGNER_ADDRESS_3, TEST_SIGNER_ADDRESS_4,
+                from_ticker, AVAX, BTC, ETH, TEST_BLOCK_TIMESTAMP, TEST_SIGNER_ADDRESS_1,
+                TEST_SIGNER_ADDRESS_2, TEST_SIGNER_ADDRESS_3, TEST_SIGNER_ADDRESS_4,
             },
             validator::Validator,
         },
         helpers::{
-            hex::{hex_to_bytes, make_feed_id},
+            hex::hex_to_bytes,
             iter_into::{IterInto, IterIntoOpt, OptIterIntoOpt},
         },
         network::error::Error,
         protocol::constants::{MAX_TIMESTAMP_AHEAD_MS, MAX_TIMESTAMP_DELAY_MS},
-        Value,
+        SignerAddress, Value,
     };
 
     #[test]
     fn test_feed_index() {
         let config = Config::test_with_signer_count_threshold_or_default(None);
 
-        let eth_index = config.feed_index(make_feed_id(ETH));
+        let eth_index = config.feed_index(from_ticker(ETH));
         assert_eq!(eth_index, 0.into());
 
-        let eth_index = config.feed_index(make_feed_id("778680")); //eth
+        let eth_index = config.feed_index(from_ticker("778680")); //eth
         assert_eq!(eth_index, None);
 
-        let btc_index = config.feed_index(make_feed_id(BTC));
+        let btc_index = config.feed_index(from_ticker(BTC));
         assert_eq!(btc_index, 1.into());
 
-        let avax_index = config.feed_index(make_feed_id(AVAX));
+        let avax_index = config.feed_index(from_ticker(AVAX));
         assert_eq!(avax_index, None);
     }
 
@@ -196,6 +240,61 @@ mod tests {
         assert_eq!(index, None);
     }
 
+    #[test]
+    fn test_indices_match_linear_search() {
+        let config = Config::test(
+            None,
+            vec![
+                TEST_SIGNER_ADDRESS_4,
+                TEST_SIGNER_ADDRESS_1,
+                TEST_SIGNER_ADDRESS_3,
+                TEST_SIGNER_ADDRESS_2,
+            ],
+            vec![BTC, AVAX, ETH],
+            None,
+            None,
+            None,
+        );
+
+        for feed_id in [ETH, BTC, AVAX, "SOL"].map(from_ticker) {
+            assert_eq!(
+                config.feed_index(feed_id),
+                config.feed_ids().iter().position(|&elt| elt == feed_id)
+            );
+        }
+
+        for signer in [
+            TEST_SIGNER_ADDRESS_1,
+            TEST_SIGNER_ADDRESS_2,
+            TEST_SIGNER_ADDRESS_3,
+            TEST_SIGNER_ADDRESS_4,
+            "2c59617248994d12816ee1fa77ce0a64eeb456bf",
+        ]
+        .map(|address| SignerAddress::from(hex_to_bytes(address.into())))
+        {
+            assert_eq!(
+                config.signer_index(&signer),
+                config.signers().iter().position(|elt| *elt == signer)
+            );
+        }
+    }
+
+    #[test]
+    fn test_signer_index_ignores_hex_case() {
+        let config = Config::test_with_signer_count_threshold_or_default(None);
+
+        for address in [
+            TEST_SIGNER_ADDRESS_2.to_lowercase(),
+            TEST_SIGNER_ADDRESS_2.to_uppercase(),
+            format!("0x{}", TEST_SIGNER_ADDRESS_2.to_uppercase()),
+        ] {
+            let signer = SignerAddress::from_str(&address).unwrap();
+
+            assert_eq!(signer, config.signers()[1]);
+            assert_eq!(config.signer_index(&signer), 1.into());
+        }
+    }
+
     #[test]
     fn test_validate_timestamp() {
         let config = Config::test_with_signer_count_threshold_or_default(None);
@@ -234,6 +333,57 @@ mod tests {
         assert_eq!(res, Err(Error::TimestampTooOld(1, timestamp)));
     }
 
+    #[test]
+    fn test_validate_timestamp_range_bounds() {
+        let config = Config::test_with_signer_count_threshold_or_default(None);
+        let (min_timestamp, max_timestamp) = config.valid_timestamp_range();
+
+        assert_eq!(
+            config.validate_timestamp(0, min_timestamp),
+            Ok(min_timestamp)
+        );
+        assert_eq!(
+            config.validate_timestamp(1, max_timestamp),
+            Ok(max_timestamp)
+        );
+
+        let too_old = (min_timestamp.as_millis() - 1).into();
+        assert_eq!(
+            config.validate_timestamp(2, too_old),
+            Err(Error::TimestampTooOld(2, too_old))
+        );
+        let too_future = max_timestamp.add(1u64);
+        assert_eq!(
+            config.validate_timestamp(3, too_future),
+            Err(Error::TimestampTooFuture(3, too_future))
+        );
+    }
+
+    #[test]
+    fn test_validate_timestamp_configured_window() {
+        let config = Config::test(
+            None,
+            vec![TEST_SIGNER_ADDRESS_1, TEST_SIGNER_ADDRESS_2],
+            vec![ETH, BTC],
+            None,
+            Some(1000.into()),
+            Some(500.into()),
+        );
+
+        assert!(config
+            .validate_timestamp(0, (TEST_BLOCK_TIMESTAMP - 1000).into())
+            .is_ok());
+        assert!(config
+            .validate_timestamp(1, (TEST_BLOCK_TIMESTAMP - 1001).into())
+            .is_err());
+        assert!(config
+            .validate_timestamp(2, (TEST_BLOCK_TIMESTAMP + 500).into())
+            .is_ok());
+        assert!(config
+            .validate_timestamp(3, (TEST_BLOCK_TIMESTAMP + 501).into())
+            .is_err());
+    }
+
     #[test]
     fn test_validate_timestamp_zero() {
         let res = Config::test_with_signer_count_threshold_or_default(None)
@@ -0,0 +1,58 @@
+//! CosmWasm extension
+//!
+//! Implementation of the config suited for the CosmWasm contracts, with the crypto operations using
+//! the `cosmwasm_crypto` library, the one backing the `secp256k1_recover_pubkey` function of the CosmWasm `Api`.
+//!
+//! The [`Crypto`] trait operates on static functions, so the `CosmwasmCrypto` can't hold the `&dyn Api` reference
+//! of the contract's `Deps` and calls the `cosmwasm_crypto` functions directly instead.
+
+use cosmwasm_crypto::{secp256k1_recover_pubkey, CryptoError as CosmwasmCryptoError};
+use sha3::{Digest, Keccak256};
+
+use crate::{
+    crypto::{Crypto, CryptoError},
+    network::StdEnv,
+    Bytes, RedStoneConfigImpl,
+};
+
+/// Implementation of `RedstoneConfig` specialized for operations on the CosmWasm.
+pub type CosmwasmRedStoneConfig = RedStoneConfigImpl<CosmwasmCrypto, CosmwasmEnv>;
+
+pub type CosmwasmEnv = StdEnv;
+pub enum CosmwasmCrypto {}
+
+impl Crypto for CosmwasmCrypto {
+    type KeccakOutput = [u8; 32];
+
+    fn keccak256(input: impl AsRef<[u8]>) -> Self::KeccakOutput {
+        Keccak256::new_with_prefix(input).finalize().into()
+    }
+
+    fn recover_public_key(
+        recovery_byte: u8,
+        signature_bytes: impl AsRef<[u8]>,
+        message_hash: Self::KeccakOutput,
+    ) -> Result<Bytes, CryptoError> {
+        let key = secp256k1_recover_pubkey(&message_hash, signature_bytes.as_ref(), recovery_byte)
+            .map_err(|error| match error {
+                CosmwasmCryptoError::InvalidHashFormat { .. } => CryptoError::RecoverPreHash,
+                CosmwasmCryptoError::InvalidRecoveryParam { .. } => {
+                    CryptoError::RecoveryByte(recovery_byte)
+                }
+                _ => CryptoError::Signature(signature_bytes.as_ref().to_vec()),
+            })?;
+
+        Ok(key.into())
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "helpers")]
+mod tests {
+    use crate::{cosmwasm::CosmwasmCrypto, crypto::recovery_key_tests::run_all_testcases};
+
+    #[test]
+    fn test_cosmwasm_crypto_impl() {
+        run_all_testcases::<CosmwasmCrypto>();
+    }
+}
@@ -0,0 +1,26 @@
+use core::future::{ready, Future};
+
+use crate::{Crypto, CryptoError, SignerAddress};
+
+/// Asynchronous counterpart of the [`Crypto`] address recovery.
+///
+/// Designed for the off-chain verifiers recovering the signers with an inherently async service, like a remote KMS.
+/// Every [`Crypto`] implementation is an `AsyncCrypto` too, resolving immediately.
+pub trait AsyncCrypto {
+    /// Recovers the address of the `signature` signer of the `message`.
+    fn recover_address(
+        &self,
+        message: &[u8],
+        signature: &[u8],
+    ) -> impl Future<Output = Result<SignerAddress, CryptoError>>;
+}
+
+impl<T: Crypto> AsyncCrypto for T {
+    fn recover_address(
+        &self,
+        message: &[u8],
+        signature: &[u8],
+    ) -> impl Future<Output = Result<SignerAddress, CryptoError>> {
+        ready(<T as Crypto>::recover_address(message, signature))
+    }
+}
@@ -3,7 +3,22 @@ use core::fmt::Debug;
 
 use primitive_types::U256;
 
-use crate::{Bytes, SignerAddress};
+use crate::{protocol::constants::SIGNATURE_BS, Bytes, SignerAddress};
+
+#[cfg(feature = "async-crypto")]
+mod async_crypto;
+#[cfg(feature = "async-crypto")]
+pub use async_crypto::AsyncCrypto;
+
+const UNCOMPRESSED_PUBLIC_KEY_BS: usize = 65;
+const COMPRESSED_PUBLIC_KEY_BS: usize = 33;
+
+const ECDSA_N: U256 = U256([
+    13822214165235122497,
+    13451932020343611451,
+    18446744073709551614,
+    18446744073709551615,
+]);
 
 const ECDSA_N_DIV_2: U256 = U256([
     16134479119472337056,
@@ -17,13 +32,38 @@ pub enum CryptoError {
     RecoveryByte(u8),
     Signature(Vec<u8>),
     RecoverPreHash,
+    /// The `r` of the signature is zero.
+    SignatureRZero,
+    /// The `s` of the signature is zero.
+    SignatureSZero,
+    /// The `r` of the signature isn't less than the order of the curve.
+    SignatureRTooLarge,
+    /// The `s` of the signature is in the upper half of the order of the curve, so the signature is malleable.
+    SignatureHighS,
+    /// The public key isn't a 65-byte uncompressed one, starting with `0x04`.
+    PublicKey(Vec<u8>),
 }
+/// The greatest code of the errors parametrized with the recovery byte or the signature length,
+/// the codes above it are reserved for the fixed ones.
+const MAX_PARAMETRIZED_CODE: u16 = 90;
+
 impl CryptoError {
+    /// Returns the code of the error, offset from the `CryptographicError` error code.
+    ///
+    /// The errors parametrized with the recovery byte or the signature length take the codes `0..=90`,
+    /// the value being clamped to `90`, so they never collide with the fixed codes:
+    /// the ones of the signature bound and malleability errors are pinned to `91..=94`,
+    /// the one of the public key error to `95`.
     pub fn code(&self) -> u16 {
         match self {
-            CryptoError::RecoveryByte(byte) => *byte as u16,
-            CryptoError::Signature(vec) => vec.len() as u16,
+            CryptoError::RecoveryByte(byte) => (*byte as u16).min(MAX_PARAMETRIZED_CODE),
+            CryptoError::Signature(vec) => vec.len().min(MAX_PARAMETRIZED_CODE as usize) as u16,
             CryptoError::RecoverPreHash => 0,
+            CryptoError::SignatureRZero => 91,
+            CryptoError::SignatureSZero => 92,
+            CryptoError::SignatureRTooLarge => 93,
+            CryptoError::SignatureHighS => 94,
+            CryptoError::PublicKey(_) => 95,
         }
     }
 }
@@ -39,6 +79,19 @@ pub trait Crypto {
         message_hash: Self::KeccakOutput,
     ) -> Result<Bytes, CryptoError>;
 
+    /// Variant of the `recover_public_key` returning the 33-byte compressed key,
+    /// the `0x02` or `0x03` prefix, depending on the parity of the `y`, followed by the `x`,
+    /// for the interop with the systems exchanging the compressed keys.
+    fn recover_compressed_public_key(
+        recovery_byte: u8,
+        signature_bytes: impl AsRef<[u8]>,
+        message_hash: Self::KeccakOutput,
+    ) -> Result<Bytes, CryptoError> {
+        let key = Self::recover_public_key(recovery_byte, signature_bytes, message_hash)?;
+
+        compress_public_key(key.as_slice())
+    }
+
     fn recover_address<A: AsRef<[u8]>, B: AsRef<[u8]>>(
         message: A,
         signature: B,
@@ -51,16 +104,89 @@ pub trait Crypto {
             &signature.as_ref()[..64],
             msg_hash,
         )?;
-        let key_hash = Self::keccak256(&key.as_ref()[1..]); // skip first uncompressed-key byte
+
+        Self::address_from_public_key(key)
+    }
+
+    /// Derives the address from the uncompressed public key, skipping the signer recovery.
+    ///
+    /// Fails with `CryptoError::PublicKey` unless the key is 65 bytes long, starting with `0x04`.
+    fn address_from_public_key(
+        uncompressed_key: impl AsRef<[u8]>,
+    ) -> Result<SignerAddress, CryptoError> {
+        let key = uncompressed_key.as_ref();
+        if key.len() != UNCOMPRESSED_PUBLIC_KEY_BS || key[0] != 0x04 {
+            return Err(CryptoError::PublicKey(key.to_vec()));
+        }
+        let key_hash = Self::keccak256(&key[1..]); // skip first uncompressed-key byte
 
         Ok(key_hash.as_ref()[12..].to_vec().into()) // last 20 bytes
     }
+
+    /// Variant of the `recover_address` taking the 64-byte `r || s` signature and the recovery byte `v` separately.
+    ///
+    /// Fails with `CryptoError::Signature` when the `rs` isn't 64 bytes long.
+    fn recover_address_with_v<A: AsRef<[u8]>, B: AsRef<[u8]>>(
+        message: A,
+        rs: B,
+        v: u8,
+    ) -> Result<SignerAddress, CryptoError> {
+        let rs = rs.as_ref();
+        if rs.len() != SIGNATURE_BS - 1 {
+            return Err(CryptoError::Signature(rs.to_vec()));
+        }
+
+        let mut signature = [0u8; SIGNATURE_BS];
+        signature[..SIGNATURE_BS - 1].copy_from_slice(rs);
+        signature[SIGNATURE_BS - 1] = v;
+
+        Self::recover_address(message, signature)
+    }
+}
+
+/// Accepts only the `0` and `1` recovery ids, for the natives accepting the raw recovery ids (`0..=3`),
+/// as the ethereum signatures don't use the other ones.
+#[cfg(any(feature = "aptos", feature = "sui"))]
+pub(crate) fn check_recovery_byte(recovery_byte: u8) -> Result<u8, CryptoError> {
+    if recovery_byte > 1 {
+        return Err(CryptoError::RecoveryByte(recovery_byte));
+    }
+
+    Ok(recovery_byte)
 }
 
-fn check_signature_malleability(sig: &[u8]) -> Result<(), CryptoError> {
-    if U256::from_big_endian(&sig[32..64]) > ECDSA_N_DIV_2 {
+/// Compresses the 65-byte uncompressed public key, starting with `0x04`, to the 33-byte one.
+fn compress_public_key(uncompressed_key: &[u8]) -> Result<Bytes, CryptoError> {
+    if uncompressed_key.len() != UNCOMPRESSED_PUBLIC_KEY_BS || uncompressed_key[0] != 0x04 {
+        return Err(CryptoError::PublicKey(uncompressed_key.to_vec()));
+    }
+
+    let mut key = Vec::with_capacity(COMPRESSED_PUBLIC_KEY_BS);
+    key.push(0x02 | (uncompressed_key[UNCOMPRESSED_PUBLIC_KEY_BS - 1] & 1));
+    key.extend_from_slice(&uncompressed_key[1..COMPRESSED_PUBLIC_KEY_BS]);
+
+    Ok(key.into())
+}
+
+pub(crate) fn check_signature_malleability(sig: &[u8]) -> Result<(), CryptoError> {
+    if sig.len() < SIGNATURE_BS {
         return Err(CryptoError::Signature(sig.to_vec()));
     }
+    let r = U256::from_big_endian(&sig[..32]);
+    let s = U256::from_big_endian(&sig[32..64]);
+
+    if r.is_zero() {
+        return Err(CryptoError::SignatureRZero);
+    }
+    if s.is_zero() {
+        return Err(CryptoError::SignatureSZero);
+    }
+    if r >= ECDSA_N {
+        return Err(CryptoError::SignatureRTooLarge);
+    }
+    if s > ECDSA_N_DIV_2 {
+        return Err(CryptoError::SignatureHighS);
+    }
 
     Ok(())
 }
@@ -73,11 +199,12 @@ pub mod recovery_key_tests {
 
     use crate::{helpers::hex::hex_to_bytes, Crypto, CryptoError};
 
-    const MESSAGE: &str = "415641580000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000d394303d018d79bf0ba000000020000001";
-    const MESSAGE_HASH: &str = "f0805644755393876d0e917e553f0c206f8bc68b7ebfe73a79d2a9e7f5a4cea6";
-    const SIG_V27: &str = "475195641dae43318e194c3d9e5fc308773d6fdf5e197e02644dfd9ca3d19e3e2bd7d8656428f7f02e658a16b8f83722169c57126cc50bec8fad188b1bac6d19";
+    pub(crate) const MESSAGE: &str = "415641580000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000d394303d018d79bf0ba000000020000001";
+    pub(crate) const MESSAGE_HASH: &str =
+        "f0805644755393876d0e917e553f0c206f8bc68b7ebfe73a79d2a9e7f5a4cea6";
+    pub(crate) const SIG_V27: &str = "475195641dae43318e194c3d9e5fc308773d6fdf5e197e02644dfd9ca3d19e3e2bd7d8656428f7f02e658a16b8f83722169c57126cc50bec8fad188b1bac6d19";
     const SIG_V28: &str = "c88242d22d88252c845b946c9957dbf3c7d59a3b69ecba2898198869f9f146ff268c3e47a11dbb05cc5198aadd659881817a59ee37e088d3253f4695927428c1";
-    const PUBLIC_KEY_V27: &str =
+    pub(crate) const PUBLIC_KEY_V27: &str =
         "04f5f035588502146774d0ccfd62ee5bf1d7f1dbb96aae33a79765c636b8ec75a36f5121931b5cc37215a7d4280c5700ca92daaaf93c32b06ca9f98b1f4ece624e";
     const PUBLIC_KEY_V28: &str =
         "04626f2ad2cfb0b41a24276d78de8959bcf45fc5e80804416e660aab2089d15e98206526e639ee19d17c8f9ae0ce3a6ff1a8ea4ab773d0fb4214e08aad7ba978c8";
@@ -91,9 +218,13 @@ pub mod recovery_key_tests {
     {
         test_recover_public_key_v27::<T>();
         test_recover_public_key_v28::<T>();
+        test_recover_compressed_public_key::<T>();
         test_recover_address_1b::<T>();
         test_recover_address_1c::<T>();
         test_signature_malleability::<T>();
+        test_signature_bounds::<T>();
+        test_recover_address_with_v::<T>();
+        test_address_from_public_key::<T>();
     }
 
     fn test_recover_public_key_v27<T>()
@@ -116,6 +247,28 @@ pub mod recovery_key_tests {
         assert_eq!(Ok(hex_to_bytes(PUBLIC_KEY_V28.into()).into()), public_key);
     }
 
+    fn test_recover_compressed_public_key<T>()
+    where
+        T: Crypto<KeccakOutput = [u8; 32]>,
+    {
+        for (recovery_byte, signature, public_key) in
+            [(0, SIG_V27, PUBLIC_KEY_V27), (1, SIG_V28, PUBLIC_KEY_V28)]
+        {
+            let compressed_key = T::recover_compressed_public_key(
+                recovery_byte,
+                hex_to_bytes(signature.into()),
+                u8_slice(MESSAGE_HASH),
+            );
+            let public_key = hex_to_bytes(public_key.into());
+            let prefix = 0x02 | (public_key[64] & 1);
+
+            assert_eq!(
+                compressed_key,
+                Ok([&[prefix][..], &public_key[1..33]].concat().into())
+            );
+        }
+    }
+
     fn test_recover_address_1b<T>()
     where
         T: Crypto<KeccakOutput = [u8; 32]>,
@@ -154,6 +307,74 @@ pub mod recovery_key_tests {
         assert_eq!(result, Err(CryptoError::RecoveryByte(74)));
     }
 
+    fn test_recover_address_with_v<T>()
+    where
+        T: Crypto<KeccakOutput = [u8; 32]>,
+    {
+        for (rs, v, address) in [
+            (SIG_V27, 0x1b, ADDRESS_V27),
+            (SIG_V28, 0x1c, ADDRESS_V28),
+            (SIG_V27, 0, ADDRESS_V27),
+            (SIG_V28, 1, ADDRESS_V28),
+        ] {
+            let result =
+                T::recover_address_with_v(hex_to_bytes(MESSAGE.into()), hex_to_bytes(rs.into()), v);
+
+            assert_eq!(result, Ok(hex_to_bytes(address.into()).into()));
+        }
+
+        let rs = hex_to_bytes(SIG_V27.to_owned() + "1b");
+        let result = T::recover_address_with_v(hex_to_bytes(MESSAGE.into()), &rs, 0x1b);
+
+        assert_eq!(result, Err(CryptoError::Signature(rs)));
+    }
+
+    fn test_address_from_public_key<T>()
+    where
+        T: Crypto<KeccakOutput = [u8; 32]>,
+    {
+        for (public_key, address) in [(PUBLIC_KEY_V27, ADDRESS_V27), (PUBLIC_KEY_V28, ADDRESS_V28)]
+        {
+            let result = T::address_from_public_key(hex_to_bytes(public_key.into()));
+
+            assert_eq!(result, Ok(hex_to_bytes(address.into()).into()));
+        }
+
+        for public_key in [
+            PUBLIC_KEY_V27[2..].to_owned(),
+            "02".to_owned() + &PUBLIC_KEY_V27[2..],
+            PUBLIC_KEY_V27[..66].to_owned(),
+        ] {
+            let public_key = hex_to_bytes(public_key);
+            let result = T::address_from_public_key(&public_key);
+
+            assert_eq!(result, Err(CryptoError::PublicKey(public_key)));
+        }
+    }
+
+    fn test_signature_bounds<T>()
+    where
+        T: Crypto<KeccakOutput = [u8; 32]>,
+    {
+        const N: &str = "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141";
+        const N_DIV_2_PLUS_1: &str =
+            "7fffffffffffffffffffffffffffffff5d576e7357a4501ddfe92f46681b20a1";
+        let zero = "00".repeat(32);
+        let (r, s) = SIG_V27.split_at(64);
+
+        for (signature, expected) in [
+            (zero.clone() + s, CryptoError::SignatureRZero),
+            (r.to_owned() + &zero, CryptoError::SignatureSZero),
+            (N.to_owned() + s, CryptoError::SignatureRTooLarge),
+            (r.to_owned() + N_DIV_2_PLUS_1, CryptoError::SignatureHighS),
+        ] {
+            let result =
+                T::recover_address(hex_to_bytes(MESSAGE.into()), hex_to_bytes(signature + "1b"));
+
+            assert_eq!(result, Err(expected));
+        }
+    }
+
     fn u8_slice<const N: usize>(str: &str) -> [u8; N] {
         hex_to_bytes(str.into()).as_slice().try_into().unwrap()
     }
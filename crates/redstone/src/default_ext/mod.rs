@@ -12,11 +12,18 @@ pub struct DefaultCrypto;
 
 type CryptoResult<T> = Result<T, CryptoError>;
 
+/// Computes the keccak256 hash of the `input`, without going through the [`Crypto`] trait.
+///
+/// The same hash as the one of the [`DefaultCrypto`], e.g. for precomputing the message hashes.
+pub fn keccak256(input: impl AsRef<[u8]>) -> [u8; 32] {
+    Keccak256::new_with_prefix(input).finalize().into()
+}
+
 impl Crypto for DefaultCrypto {
     type KeccakOutput = [u8; 32];
 
     fn keccak256(input: impl AsRef<[u8]>) -> Self::KeccakOutput {
-        Keccak256::new_with_prefix(input).finalize().into()
+        keccak256(input)
     }
 
     fn recover_public_key(
@@ -24,28 +31,81 @@ impl Crypto for DefaultCrypto {
         signature_bytes: impl AsRef<[u8]>,
         message_hash: Self::KeccakOutput,
     ) -> CryptoResult<Bytes> {
-        let recovery_id =
-            RecoveryId::from_byte(recovery_byte).ok_or(CryptoError::RecoveryByte(recovery_byte))?;
+        let recovered_key = recover_verifying_key(recovery_byte, signature_bytes, message_hash)?
+            .to_encoded_point(false);
 
-        let signature = Signature::try_from(signature_bytes.as_ref())
-            .map_err(|_| CryptoError::Signature(signature_bytes.as_ref().to_vec()))?;
+        Ok(recovered_key.as_bytes().to_vec().into())
+    }
 
-        let recovered_key =
-            VerifyingKey::recover_from_prehash(message_hash.as_ref(), &signature, recovery_id)
-                .map(|key| key.to_encoded_point(false))
-                .map_err(|_| CryptoError::RecoverPreHash)?;
+    fn recover_compressed_public_key(
+        recovery_byte: u8,
+        signature_bytes: impl AsRef<[u8]>,
+        message_hash: Self::KeccakOutput,
+    ) -> CryptoResult<Bytes> {
+        let recovered_key = recover_verifying_key(recovery_byte, signature_bytes, message_hash)?
+            .to_encoded_point(true);
 
         Ok(recovered_key.as_bytes().to_vec().into())
     }
 }
 
+fn recover_verifying_key(
+    recovery_byte: u8,
+    signature_bytes: impl AsRef<[u8]>,
+    message_hash: [u8; 32],
+) -> CryptoResult<VerifyingKey> {
+    let recovery_id =
+        RecoveryId::from_byte(recovery_byte).ok_or(CryptoError::RecoveryByte(recovery_byte))?;
+
+    let signature = Signature::try_from(signature_bytes.as_ref())
+        .map_err(|_| CryptoError::Signature(signature_bytes.as_ref().to_vec()))?;
+
+    VerifyingKey::recover_from_prehash(message_hash.as_ref(), &signature, recovery_id)
+        .map_err(|_| CryptoError::RecoverPreHash)
+}
+
 #[cfg(test)]
 #[cfg(feature = "helpers")]
 mod test {
-    use crate::{crypto::recovery_key_tests::run_all_testcases, default_ext::DefaultCrypto};
+    use k256::ecdsa::VerifyingKey;
+
+    use crate::{
+        crypto::recovery_key_tests::{
+            run_all_testcases, MESSAGE, MESSAGE_HASH, PUBLIC_KEY_V27, SIG_V27,
+        },
+        default_ext::{keccak256, DefaultCrypto},
+        helpers::hex::hex_to_bytes,
+        Crypto,
+    };
 
     #[test]
     fn test_default_crypto_impl() {
         run_all_testcases::<DefaultCrypto>();
     }
+
+    #[test]
+    fn test_compressed_public_key_decompresses() {
+        let compressed_key = DefaultCrypto::recover_compressed_public_key(
+            0,
+            hex_to_bytes(SIG_V27.into()),
+            hex_to_bytes(MESSAGE_HASH.into()).try_into().unwrap(),
+        )
+        .unwrap();
+        let key = VerifyingKey::from_sec1_bytes(compressed_key.as_slice()).unwrap();
+
+        assert_eq!(compressed_key.as_slice().len(), 33);
+        assert_eq!(
+            key.to_encoded_point(false).as_bytes(),
+            hex_to_bytes(PUBLIC_KEY_V27.into())
+        );
+    }
+
+    #[test]
+    fn test_keccak256() {
+        let message = hex_to_bytes(MESSAGE.into());
+        let hash = keccak256(&message);
+
+        assert_eq!(hash.to_vec(), hex_to_bytes(MESSAGE_HASH.into()));
+        assert_eq!(hash, DefaultCrypto::keccak256(&message));
+    }
 }
@@ -0,0 +1,82 @@
+//! EVM extension
+//!
+//! Implementation of the config suited for EVM hosts (like revm simulations or EVM-targeted contracts),
+//! with the address recovery following the semantics of the `ecrecover` precompile.
+
+use revm_precompile::{primitives::Bytes as EvmBytes, secp256k1::ec_recover_run};
+
+use crate::{
+    crypto::{check_signature_malleability, Crypto, CryptoError},
+    default_ext::DefaultCrypto,
+    network::StdEnv,
+    Bytes, RedStoneConfigImpl, SignerAddress,
+};
+
+/// Implementation of `RedstoneConfig` specialized for operations on the EVM hosts.
+pub type EvmRedStoneConfig = RedStoneConfigImpl<EvmCrypto, EvmEnv>;
+
+pub type EvmEnv = StdEnv;
+
+/// Crypto operations matching the EVM ones.
+///
+/// The keccak is computed by the `sha3` crate, and the address is recovered by the `ecrecover` precompile,
+/// which only accepts the `27`/`28` recovery bytes. The public key recovery, which is not exposed by the precompile,
+/// is delegated to the [`DefaultCrypto`].
+pub enum EvmCrypto {}
+
+impl Crypto for EvmCrypto {
+    type KeccakOutput = [u8; 32];
+
+    fn keccak256(input: impl AsRef<[u8]>) -> Self::KeccakOutput {
+        DefaultCrypto::keccak256(input)
+    }
+
+    fn recover_public_key(
+        recovery_byte: u8,
+        signature_bytes: impl AsRef<[u8]>,
+        message_hash: Self::KeccakOutput,
+    ) -> Result<Bytes, CryptoError> {
+        DefaultCrypto::recover_public_key(recovery_byte, signature_bytes, message_hash)
+    }
+
+    fn recover_address<A: AsRef<[u8]>, B: AsRef<[u8]>>(
+        message: A,
+        signature: B,
+    ) -> Result<SignerAddress, CryptoError> {
+        let signature = signature.as_ref();
+        check_signature_malleability(signature)?;
+        let recovery_byte = signature[64]; // 65-byte representation
+        let recovery_id = recovery_byte - (if recovery_byte >= 27 { 27 } else { 0 });
+        if recovery_id > 1 {
+            return Err(CryptoError::RecoveryByte(recovery_id));
+        }
+
+        // precompile input: hash (32 bytes) | v (32 bytes) | r (32 bytes) | s (32 bytes)
+        let mut input = [0u8; 128];
+        input[..32].copy_from_slice(&Self::keccak256(message));
+        input[63] = recovery_id + 27;
+        input[64..].copy_from_slice(&signature[..64]);
+
+        let output = ec_recover_run(&EvmBytes::copy_from_slice(&input), u64::MAX)
+            .map_err(|_| CryptoError::RecoverPreHash)?;
+
+        // the precompile returns an empty output for the signatures it couldn't recover,
+        // or the address left-padded to 32 bytes.
+        if output.bytes.len() != 32 {
+            return Err(CryptoError::RecoverPreHash);
+        }
+
+        Ok(output.bytes[12..].to_vec().into()) // last 20 bytes
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "helpers")]
+mod tests {
+    use crate::{crypto::recovery_key_tests::run_all_testcases, evm::EvmCrypto};
+
+    #[test]
+    fn test_evm_crypto_impl() {
+        run_all_testcases::<EvmCrypto>();
+    }
+}
@@ -0,0 +1,278 @@
+use alloc::vec::Vec;
+
+use crate::{
+    protocol::{
+        constants::{
+            DATA_PACKAGES_COUNT_BS, DATA_POINTS_COUNT_BS, DATA_POINT_VALUE_BYTE_SIZE_BS,
+            REDSTONE_MARKER, SIGNATURE_BS, TIMESTAMP_BS, UNSIGNED_METADATA_BYTE_SIZE_BS,
+        },
+        data_package::DataPackage,
+    },
+    types::VALUE_SIZE,
+    SignerAddress,
+};
+
+/// Encodes the data packages to the payload bytes in the layout decoded by the [`crate::protocol::PayloadDecoder`].
+///
+/// The data packages and their data points are decoded from the end of the payload,
+/// so they are encoded in the reversed order, to be decoded back in the order they're passed.
+pub struct PayloadEncoder {
+    value_size: usize,
+}
+
+impl Default for PayloadEncoder {
+    fn default() -> Self {
+        Self {
+            value_size: VALUE_SIZE,
+        }
+    }
+}
+
+impl PayloadEncoder {
+    /// Sets the number of bytes the values are encoded with, `VALUE_SIZE` by default.
+    ///
+    /// A larger size makes the payload undecodable, which can be used to build the edge-case payloads.
+    pub fn with_value_size(mut self, value_size: usize) -> Self {
+        self.value_size = value_size;
+
+        self
+    }
+
+    /// Encodes the data packages to the marked payload, without the unsigned metadata.
+    ///
+    /// The `sign` closure gets the signer address of the data package and its signable bytes,
+    /// and returns the 65-byte signature recoverable to the signer address.
+    pub fn encode(
+        &self,
+        data_packages: &[DataPackage],
+        mut sign: impl FnMut(&SignerAddress, &[u8]) -> Vec<u8>,
+    ) -> Vec<u8> {
+        let mut bytes = Vec::new();
+
+        for data_package in data_packages.iter().rev() {
+            let signable_bytes = self.encode_signable_bytes(data_package);
+            let signature = sign(&data_package.signer_address, &signable_bytes);
+            assert_eq!(signature.len(), SIGNATURE_BS, "Wrong signature size");
+
+            bytes.extend(signable_bytes);
+            bytes.extend(signature);
+        }
+
+        bytes.extend(encode_number(
+            data_packages.len() as u64,
+            DATA_PACKAGES_COUNT_BS,
+        ));
+        bytes.extend(encode_number(0, UNSIGNED_METADATA_BYTE_SIZE_BS));
+        bytes.extend(REDSTONE_MARKER);
+
+        bytes
+    }
+
+    fn encode_signable_bytes(&self, data_package: &DataPackage) -> Vec<u8> {
+        let mut bytes = Vec::new();
+
+        for data_point in data_package.data_points.iter().rev() {
+            bytes.extend(data_point.feed_id.as_ref());
+            bytes.extend(encode_value(
+                data_point.value.as_be_bytes(),
+                self.value_size,
+            ));
+        }
+
+        bytes.extend(encode_number(
+            data_package.timestamp.as_millis(),
+            TIMESTAMP_BS,
+        ));
+        bytes.extend(encode_number(
+            self.value_size as u64,
+            DATA_POINT_VALUE_BYTE_SIZE_BS,
+        ));
+        bytes.extend(encode_number(
+            data_package.data_points.len() as u64,
+            DATA_POINTS_COUNT_BS,
+        ));
+
+        bytes
+    }
+}
+
+fn encode_number(number: u64, size: usize) -> Vec<u8> {
+    encode_value(&number.to_be_bytes(), size)
+}
+
+fn encode_value(be_bytes: &[u8], size: usize) -> Vec<u8> {
+    let significant = be_bytes
+        .iter()
+        .position(|&byte| byte != 0)
+        .unwrap_or(be_bytes.len());
+    assert!(
+        be_bytes.len() - significant <= size,
+        "Number too big for {} bytes",
+        size
+    );
+
+    let mut bytes = vec![0; size];
+    bytes[size - (be_bytes.len() - significant)..].copy_from_slice(&be_bytes[significant..]);
+
+    bytes
+}
+
+#[cfg(test)]
+#[cfg(feature = "default-crypto")]
+mod tests {
+    use alloc::vec::Vec;
+
+    use k256::ecdsa::SigningKey;
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    use super::PayloadEncoder;
+    use crate::{
+        core::{config::Config, process_payload, test_helpers::TEST_BLOCK_TIMESTAMP},
+        crypto::Crypto,
+        default_ext::{DefaultCrypto, StdRedStoneConfig},
+        helpers::hex::{hex_to_bytes, make_signer_address, sample_payload_bytes},
+        network::{error::Error, StdEnv},
+        protocol::{
+            constants::{DATA_FEED_ID_BS, SIGNATURE_BS},
+            data_package::DataPackage,
+            PayloadDecoder,
+        },
+        types::VALUE_SIZE,
+        FeedId, SignerAddress, Value,
+    };
+
+    type TestDecoder = PayloadDecoder<StdEnv, DefaultCrypto>;
+
+    // The well-known development keys, not holding any funds.
+    const PRIVATE_KEY_1: &str = "ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+    const SIGNER_ADDRESS_1: &str = "f39fd6e51aad88f6f4ce6ab8827279cfffb92266";
+    const PRIVATE_KEY_2: &str = "59c6995e998f97a5a0044966f0945389dc9e86dae88c7a8412f4603b6b78690d";
+    const SIGNER_ADDRESS_2: &str = "70997970c51812dc3a010c7d01b50e0d17dc79c8";
+
+    fn sign(signer_address: &SignerAddress, message: &[u8]) -> Vec<u8> {
+        let private_key = match signer_address {
+            address if *address == make_signer_address(SIGNER_ADDRESS_1) => PRIVATE_KEY_1,
+            address if *address == make_signer_address(SIGNER_ADDRESS_2) => PRIVATE_KEY_2,
+            address => panic!("Unknown signer {:?}", address),
+        };
+        let key = SigningKey::from_slice(&hex_to_bytes(private_key.into())).unwrap();
+        let (signature, recovery_id) = key
+            .sign_prehash_recoverable(&DefaultCrypto::keccak256(message))
+            .unwrap();
+
+        let mut bytes = signature.to_bytes().to_vec();
+        bytes.push(recovery_id.to_byte() + 27);
+
+        bytes
+    }
+
+    fn test_data_packages() -> Vec<DataPackage> {
+        vec![
+            DataPackage::test_multi_data_point(
+                vec![("ETH", 236389750361), ("BTC", 4239478293427)],
+                SIGNER_ADDRESS_1,
+                Some(1707307760000),
+            ),
+            DataPackage::test_single_data_point("AVAX", 3648219, SIGNER_ADDRESS_2, None),
+        ]
+    }
+
+    #[test]
+    fn test_encode_decode_round_trip() {
+        let data_packages = test_data_packages();
+        let mut bytes = PayloadEncoder::default().encode(&data_packages, sign);
+
+        let payload = TestDecoder::make_payload(&mut bytes).unwrap();
+
+        assert_eq!(payload.data_packages, data_packages);
+    }
+
+    #[test]
+    fn test_encode_decode_round_trip_narrow_values() {
+        let data_packages = test_data_packages();
+        let mut bytes = PayloadEncoder::default()
+            .with_value_size(8)
+            .encode(&data_packages, sign);
+
+        let payload = TestDecoder::make_payload(&mut bytes).unwrap();
+
+        assert_eq!(payload.data_packages, data_packages);
+    }
+
+    #[test]
+    fn test_encode_decode_hash_feed_id() {
+        let mut hash = [0xab; VALUE_SIZE];
+        hash[0] = 0;
+        let feed_id = FeedId::from(hash);
+        let data_packages: Vec<_> = [(SIGNER_ADDRESS_1, 11), (SIGNER_ADDRESS_2, 13)]
+            .into_iter()
+            .map(|(signer, value)| {
+                let mut data_package =
+                    DataPackage::test_single_data_point("ETH", value, signer, None);
+                data_package.data_points[0].feed_id = feed_id;
+
+                data_package
+            })
+            .collect();
+        let bytes = PayloadEncoder::default().encode(&data_packages, sign);
+
+        let payload = TestDecoder::make_payload(&mut bytes.clone()).unwrap();
+
+        assert_eq!(payload.data_packages, data_packages);
+        assert_eq!(payload.feed_set(), [feed_id].into());
+
+        let config: StdRedStoneConfig = Config::try_new(
+            2,
+            [SIGNER_ADDRESS_1, SIGNER_ADDRESS_2]
+                .map(make_signer_address)
+                .to_vec(),
+            vec![feed_id],
+            TEST_BLOCK_TIMESTAMP.into(),
+            None,
+            None,
+        )
+        .unwrap()
+        .into();
+        let result = process_payload(&config, bytes).unwrap();
+
+        assert_eq!(result.values, vec![Value::from(12u8)]);
+        assert_eq!(result.signer_coverage, vec![(feed_id, 2)]);
+    }
+
+    #[test]
+    fn test_encode_too_wide_values() {
+        let mut bytes = PayloadEncoder::default()
+            .with_value_size(33)
+            .encode(&test_data_packages(), sign);
+
+        let result = TestDecoder::make_payload(&mut bytes);
+
+        assert_eq!(result.unwrap_err(), Error::SizeNotSupported(33));
+    }
+
+    #[test]
+    fn test_encode_sample_layout() {
+        let mut bytes = sample_payload_bytes();
+        let payload = TestDecoder::make_payload(&mut bytes).unwrap();
+        let mut signatures = sample_signatures();
+
+        let encoded = PayloadEncoder::default()
+            .encode(&payload.data_packages, |_, _| signatures.pop().unwrap());
+
+        assert_eq!(encoded, sample_payload_bytes());
+    }
+
+    /// The signatures of the sample data packages, reversed to be popped in the payload order.
+    fn sample_signatures() -> Vec<Vec<u8>> {
+        let mut bytes = sample_payload_bytes();
+        let package_count = 15;
+        let package_size = DATA_FEED_ID_BS + VALUE_SIZE + 13 + SIGNATURE_BS;
+        bytes.truncate(package_count * package_size);
+
+        (0..package_count)
+            .map(|i| bytes[(i + 1) * package_size - SIGNATURE_BS..(i + 1) * package_size].to_vec())
+            .rev()
+            .collect()
+    }
+}
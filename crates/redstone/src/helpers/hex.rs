@@ -7,6 +7,8 @@ use hex::{decode, encode};
 
 use crate::{Bytes, FeedId, SignerAddress};
 const SAMPLE_PAYLOAD_HEX: &str = include_str!("../../../.././sample-data/payload.hex");
+const SAMPLE_LARGE_VALUE_PAYLOAD_HEX: &str =
+    include_str!("../../../.././sample-data/payload_large_value.hex");
 
 pub fn hex_to_bytes(hex_str: String) -> Vec<u8> {
     let trimmed_hex = hex_str.trim_start_matches("0x");
@@ -24,8 +26,10 @@ pub fn make_bytes(vec: Vec<&str>, fun: fn(&str) -> String) -> Vec<Bytes> {
         .collect()
 }
 
+/// Panicking variant of the [`FeedId::from_ticker`], for the tests.
+#[deprecated(note = "use FeedId::from_ticker")]
 pub fn make_feed_id(s: &str) -> FeedId {
-    hex_to_bytes(encode(s)).into()
+    FeedId::from_ticker(s).expect("Conversion error")
 }
 
 pub fn make_signer_address(s: &str) -> SignerAddress {
@@ -41,3 +45,9 @@ pub fn sample_payload_bytes() -> Vec<u8> {
 
     hex_to_bytes(contents)
 }
+
+/// The payload of two data packages signed by the well-known development keys,
+/// with the LARGE feed value of `u128::MAX + 123456790`, exceeding the `u128`.
+pub fn sample_large_value_payload_bytes() -> Vec<u8> {
+    hex_to_bytes(SAMPLE_LARGE_VALUE_PAYLOAD_HEX.to_string())
+}
@@ -1,6 +1,6 @@
 use alloc::vec::Vec;
 
-use super::hex::{hex_to_bytes, make_feed_id};
+use super::hex::hex_to_bytes;
 use crate::{FeedId, SignerAddress};
 
 pub trait IterInto<U> {
@@ -50,7 +50,11 @@ macro_rules! impl_iter_into_with_converter {
         )*
     };
 }
-impl_iter_into_with_converter!((SignerAddress, hex_to_bytes), (FeedId, make_feed_id));
+impl_iter_into_with_converter!(
+    (SignerAddress, hex_to_bytes),
+    (FeedId, |ticker: &str| FeedId::from_ticker(ticker)
+        .expect("Conversion error"))
+);
 
 #[cfg(test)]
 mod iter_into_tests {
@@ -1,2 +1,3 @@
+pub mod encoder;
 pub mod hex;
 pub mod iter_into;
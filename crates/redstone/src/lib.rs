@@ -15,6 +15,7 @@ extern crate alloc;
 pub mod contract;
 pub mod core;
 mod crypto;
+pub mod math;
 pub mod network;
 mod protocol;
 mod types;
@@ -29,13 +30,43 @@ pub mod casper;
 #[cfg(feature = "radix")]
 pub mod radix;
 
+#[cfg(feature = "near")]
+pub mod near;
+
+#[cfg(feature = "evm")]
+pub mod evm;
+
+#[cfg(feature = "cosmwasm")]
+pub mod cosmwasm;
+
+#[cfg(feature = "aptos")]
+pub mod aptos;
+
+#[cfg(feature = "sui")]
+pub mod sui;
+
+#[cfg(feature = "ton")]
+pub mod ton;
+
+#[cfg(feature = "starknet")]
+pub mod starknet;
+
 use ::core::marker::PhantomData;
 #[cfg(feature = "default-crypto")]
 pub mod default_ext;
 
+#[cfg(feature = "async-crypto")]
+pub use crypto::AsyncCrypto;
 pub use crypto::{Crypto, CryptoError};
+#[cfg(feature = "default-crypto")]
+pub use default_ext::keccak256;
 use network::Environment;
+pub use protocol::{
+    constants::REDSTONE_MARKER, data_package::DataPackage, data_point::DataPoint, payload::Payload,
+    read_redstone_marker,
+};
 pub use types::{Bytes, FeedId, SignerAddress, TimestampMillis, Value};
+pub use utils::hex::try_hex_to_bytes;
 
 use crate::core::config::Config;
 
@@ -0,0 +1,127 @@
+//! Math utilities used by the values aggregation, exposed for the custom aggregations.
+
+use alloc::vec::Vec;
+
+use primitive_types::U256;
+
+use crate::{utils::median::Median, Value};
+
+pub use crate::utils::median::MedianKind;
+
+/// Returns the median of the values, or `None` for no values.
+///
+/// For an odd number of values the median is the middle one of the sorted values,
+/// for an even number of values it's the average of the two middle ones, rounded down.
+///
+/// ```
+/// use redstone::{math::median, Value};
+///
+/// assert_eq!(median(&[]), None);
+/// assert_eq!(median(&[3u8, 1, 2].map(Value::from)), Some(Value::from(2u8)));
+/// assert_eq!(median(&[4u8, 1, 2, 3].map(Value::from)), Some(Value::from(2u8)));
+/// ```
+pub fn median(values: &[Value]) -> Option<Value> {
+    median_u256(
+        &values
+            .iter()
+            .map(|value| value.to_u256())
+            .collect::<Vec<_>>(),
+    )
+    .map(Value::from_u256)
+}
+
+/// The [`median`] of the values along with its [`MedianKind`], or `None` for no values.
+///
+/// ```
+/// use redstone::{
+///     math::{median_with_kind, MedianKind},
+///     Value,
+/// };
+///
+/// assert_eq!(
+///     median_with_kind(&[3u8, 1, 2].map(Value::from)),
+///     Some((Value::from(2u8), MedianKind::Exact))
+/// );
+/// assert_eq!(
+///     median_with_kind(&[4u8, 1, 2, 3].map(Value::from)),
+///     Some((Value::from(2u8), MedianKind::Interpolated))
+/// );
+/// ```
+pub fn median_with_kind(values: &[Value]) -> Option<(Value, MedianKind)> {
+    values
+        .iter()
+        .map(|value| value.to_u256())
+        .collect::<Vec<_>>()
+        .median_with_kind()
+        .map(|(median, kind)| (Value::from_u256(median), kind))
+}
+
+/// The [`median`] of the `U256` values.
+///
+/// ```
+/// use primitive_types::U256;
+/// use redstone::math::median_u256;
+///
+/// assert_eq!(median_u256(&[U256::from(1), U256::from(4)]), Some(U256::from(2)));
+/// ```
+pub fn median_u256(values: &[U256]) -> Option<U256> {
+    values.to_vec().median()
+}
+
+#[cfg(test)]
+mod tests {
+    use primitive_types::U256;
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    use super::{median, median_u256, median_with_kind, MedianKind};
+    use crate::Value;
+
+    fn values<const N: usize>(numbers: [u128; N]) -> [Value; N] {
+        numbers.map(Value::from)
+    }
+
+    #[test]
+    fn test_median_empty() {
+        assert_eq!(median(&[]), None);
+        assert_eq!(median_u256(&[]), None);
+    }
+
+    #[test]
+    fn test_median_single() {
+        assert_eq!(median(&values([7])), Some(7u8.into()));
+    }
+
+    #[test]
+    fn test_median_odd() {
+        assert_eq!(median(&values([9, 1, 5])), Some(5u8.into()));
+        assert_eq!(median(&values([9, 1, 5, 3, 7])), Some(5u8.into()));
+    }
+
+    #[test]
+    fn test_median_even() {
+        assert_eq!(median(&values([1, 4])), Some(2u8.into()));
+        assert_eq!(median(&values([8, 1, 6, 3])), Some(4u8.into()));
+    }
+
+    #[test]
+    fn test_median_with_kind() {
+        assert_eq!(median_with_kind(&[]), None);
+        assert_eq!(
+            median_with_kind(&values([9, 1, 5])),
+            Some((5u8.into(), MedianKind::Exact))
+        );
+        assert_eq!(
+            median_with_kind(&values([8, 1, 6, 3])),
+            Some((4u8.into(), MedianKind::Interpolated))
+        );
+    }
+
+    #[test]
+    fn test_median_u256_near_max() {
+        assert_eq!(
+            median_u256(&[U256::MAX, U256::MAX - 2]),
+            Some(U256::MAX - 1)
+        );
+    }
+}
@@ -0,0 +1,57 @@
+//! NEAR extension
+//!
+//! Implementation of the config suited for the NEAR network, with the crypto operations using
+//! the `keccak256` and `ecrecover` host functions exposed by `near_sdk::env`.
+
+use near_sdk::env::{ecrecover, keccak256_array};
+
+use crate::{
+    crypto::{Crypto, CryptoError},
+    network::StdEnv,
+    Bytes, RedStoneConfigImpl,
+};
+
+/// Implementation of `RedstoneConfig` specialized for operations on the NEAR.
+pub type NearRedStoneConfig = RedStoneConfigImpl<NearCrypto, NearEnv>;
+
+pub type NearEnv = StdEnv;
+pub enum NearCrypto {}
+
+impl Crypto for NearCrypto {
+    type KeccakOutput = [u8; 32];
+
+    fn keccak256(input: impl AsRef<[u8]>) -> Self::KeccakOutput {
+        keccak256_array(input.as_ref())
+    }
+
+    fn recover_public_key(
+        recovery_byte: u8,
+        signature_bytes: impl AsRef<[u8]>,
+        message_hash: Self::KeccakOutput,
+    ) -> Result<Bytes, CryptoError> {
+        // The host function accepts recovery ids up to 3, but only 0 and 1 are valid for ethereum signatures.
+        if recovery_byte > 1 {
+            return Err(CryptoError::RecoveryByte(recovery_byte));
+        }
+
+        let key = ecrecover(&message_hash, signature_bytes.as_ref(), recovery_byte, true)
+            .ok_or_else(|| CryptoError::Signature(signature_bytes.as_ref().to_vec()))?;
+
+        let mut uncompressed_key = vec![0x04];
+        uncompressed_key.extend_from_slice(&key);
+
+        Ok(uncompressed_key.into())
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "helpers")]
+#[cfg(feature = "near-test")]
+mod tests {
+    use crate::{crypto::recovery_key_tests::run_all_testcases, near::NearCrypto};
+
+    #[test]
+    fn test_default_crypto_impl() {
+        run_all_testcases::<NearCrypto>();
+    }
+}
@@ -3,10 +3,14 @@ use core::fmt::{Debug, Display, Formatter};
 
 use crate::{
     network::as_str::{AsAsciiStr, AsHexStr},
-    types::Value,
+    protocol::constants::{REDSTONE_MARKER, SIGNER_ADDRESS_BS},
+    types::{Value, VALUE_SIZE},
     CryptoError, FeedId, SignerAddress, TimestampMillis,
 };
 
+/// The [`CryptoError::code`] is added to 700 and clamped so that it stays in the 700-799 band.
+const CRYPTO_ERROR_CODE_MAX_OFFSET: u16 = 99;
+
 #[derive(Clone, Eq, PartialEq, Debug)]
 pub struct ContractErrorContent {
     pub code: u8,
@@ -17,6 +21,30 @@ pub struct ContractErrorContent {
 ///
 /// These errors include issues with contract logic, data types,
 /// cryptographic operations, and conditions specific to the requirements.
+///
+/// Each error has a numeric [`Error::code`], used by the on-chain consumers, so the codes must stay stable:
+///
+/// | Code         | Error                                                        |
+/// |--------------|--------------------------------------------------------------|
+/// | 0-255        | `ContractError`, the code given by the contract              |
+/// | 509-530      | Fixed codes of the payload, config and parsing errors        |
+/// | 600 + size   | `SizeNotSupported`                                           |
+/// | 700-799      | `CryptographicError`, `700 + CryptoError::code` up to 799    |
+/// | 1000 + index | `TimestampTooOld`, with the data package index               |
+/// | 1050 + index | `TimestampTooFuture`, with the data package index            |
+/// | 1101-1102    | Fixed codes of the price adapter errors                      |
+/// | 2000 + ...   | `InsufficientSignerCount`, `2000 + index * 10 + value`       |
+///
+/// New variants get the next free fixed code; the existing codes must not be changed.
+/// The range of the fixed codes above is kept in sync with the [`Error::from_code`]:
+///
+/// ```
+/// use redstone::network::error::Error;
+///
+/// assert!((509..=530).all(|code| Error::from_code(code).is_some()));
+/// assert_eq!(Error::from_code(508), None);
+/// assert_eq!(Error::from_code(531), None);
+/// ```
 #[derive(Clone, Eq, PartialEq, Debug)]
 pub enum Error {
     /// Represents errors that arise from the contract itself.
@@ -53,6 +81,8 @@ pub enum Error {
     ///
     /// This error is specific to scenarios where marker or identifier bytes do not match
     /// expected values, potentially indicating corrupted or tampered data.
+    ///
+    /// Includes the actual marker bytes, the expected ones are the [`crate::REDSTONE_MARKER`].
     WrongRedStoneMarker(Vec<u8>),
 
     /// Used when there is leftover data in a payload that should have been empty.
@@ -113,6 +143,61 @@ pub enum Error {
     /// Contains the first timestamp and the one that is not equal to the first one.
     TimestampDifferentThanOthers(TimestampMillis, TimestampMillis),
 
+    /// Indicates that a string is not a valid hex representation of bytes.
+    ///
+    /// Includes the offending part of the input.
+    InvalidHex(String),
+
+    /// Indicates that a parsed signer address has a wrong length.
+    ///
+    /// Includes the length of the parsed address in bytes.
+    InvalidSignerAddressLength(usize),
+
+    /// Indicates that a parsed feed id is empty or doesn't fit into the `FeedId`.
+    ///
+    /// Includes the length of the parsed feed id in bytes.
+    InvalidFeedIdLength(usize),
+
+    /// Indicates that the data is older than allowed by the data time-to-live.
+    ///
+    /// Includes the timestamp of the stale data and the current time.
+    DataStaleness(TimestampMillis, TimestampMillis),
+
+    /// Indicates that the data of the feed is older than allowed by the data time-to-live.
+    ///
+    /// Includes the stale FeedId, the timestamp of its data and the current time.
+    FeedDataStaleness(FeedId, TimestampMillis, TimestampMillis),
+
+    /// Indicates that the aggregated value of the feed is out of the bounds configured for the feed.
+    ///
+    /// Includes the FeedId and its aggregated value.
+    ValueOutOfBounds(FeedId, Value),
+
+    /// Indicates that a buffer is too small for the data, like the caller-provided scratch buffer
+    /// or the payload shorter than its declared unsigned metadata.
+    ///
+    /// Includes the required and the available size of the buffer in bytes.
+    BufferOverflow(usize, usize),
+
+    /// Indicates that a requested FeedId is missing on config feed_ids list.
+    ///
+    /// Includes FeedId that is missing.
+    ConfigInvalidFeedId(FeedId),
+
+    /// Indicates that the payload is empty or consists of the RedStone marker only.
+    EmptyPayload,
+
+    /// Indicates that the bytes of the serialized config are malformed.
+    ///
+    /// Includes the offset of the malformed part of the bytes.
+    ConfigMalformedBytes(usize),
+
+    /// Indicates that the config feed list is larger than the maximum allowed feed count,
+    /// as the feed count is serialized in 2 bytes by the `Config::to_bytes`.
+    ///
+    /// Includes current config feed list length and maximum allowed feed count per config.
+    ConfigExceededFeedCount(usize, usize),
+
     /// Indicates that the provided data timestamp is not greater than a previously written package timestamp.
     ///
     /// For the price adapter to accept a new price update, the associated timestamp must be
@@ -154,11 +239,24 @@ impl Error {
             Error::ConfigEmptyFeedIds => 517,
             Error::ConfigReocuringFeedId(_) => 518,
             Error::TimestampDifferentThanOthers(_, _) => 519,
+            Error::InvalidHex(_) => 520,
+            Error::InvalidSignerAddressLength(_) => 521,
+            Error::InvalidFeedIdLength(_) => 522,
+            Error::DataStaleness(_, _) => 523,
+            Error::FeedDataStaleness(_, _, _) => 524,
+            Error::ValueOutOfBounds(_, _) => 525,
+            Error::BufferOverflow(_, _) => 526,
+            Error::ConfigInvalidFeedId(_) => 527,
+            Error::EmptyPayload => 528,
+            Error::ConfigMalformedBytes(_) => 529,
+            Error::ConfigExceededFeedCount(_, _) => 530,
             Error::InsufficientSignerCount(data_package_index, value, _) => {
                 (2000 + data_package_index * 10 + value) as u16
             }
             Error::SizeNotSupported(size) => 600 + *size as u16,
-            Error::CryptographicError(error) => 700 + error.code(),
+            Error::CryptographicError(error) => {
+                700 + error.code().min(CRYPTO_ERROR_CODE_MAX_OFFSET)
+            }
             Error::TimestampTooOld(data_package_index, _) => 1000 + *data_package_index as u16,
             Error::TimestampTooFuture(data_package_index, _) => 1050 + *data_package_index as u16,
             Error::DataTimestampMustBeGreaterThanBefore(_, _) => 1101,
@@ -167,6 +265,50 @@ impl Error {
     }
 }
 
+impl Error {
+    /// Reverse lookup of the [`Error::code`], returning the name of the error variant, for debugging purposes.
+    ///
+    /// The parametrized codes are resolved according to the ranges documented on the [`Error`],
+    /// so a code beyond the range of its variant (e.g. for a data package index greater than 49) can be misattributed.
+    pub fn from_code(code: u16) -> Option<&'static str> {
+        let name = match code {
+            0..=255 => "ContractError",
+            509 => "NumberOverflow",
+            510 => "ArrayIsEmpty",
+            511 => "WrongRedStoneMarker",
+            512 => "NonEmptyPayloadRemainder",
+            513 => "ReocuringFeedId",
+            514 => "ConfigInsufficientSignerCount",
+            515 => "ConfigExceededSignerCount",
+            516 => "ConfigReocuringSigner",
+            517 => "ConfigEmptyFeedIds",
+            518 => "ConfigReocuringFeedId",
+            519 => "TimestampDifferentThanOthers",
+            520 => "InvalidHex",
+            521 => "InvalidSignerAddressLength",
+            522 => "InvalidFeedIdLength",
+            523 => "DataStaleness",
+            524 => "FeedDataStaleness",
+            525 => "ValueOutOfBounds",
+            526 => "BufferOverflow",
+            527 => "ConfigInvalidFeedId",
+            528 => "EmptyPayload",
+            529 => "ConfigMalformedBytes",
+            530 => "ConfigExceededFeedCount",
+            600..=699 => "SizeNotSupported",
+            700..=799 => "CryptographicError",
+            1000..=1049 => "TimestampTooOld",
+            1050..=1099 => "TimestampTooFuture",
+            1101 => "DataTimestampMustBeGreaterThanBefore",
+            1102 => "CurrentTimestampMustBeGreaterThanLatestUpdateTimestamp",
+            2000.. => "InsufficientSignerCount",
+            _ => return None,
+        };
+
+        Some(name)
+    }
+}
+
 impl Display for Error {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         match self {
@@ -176,7 +318,12 @@ impl Display for Error {
             Error::CryptographicError(error) => write!(f, "Cryptographic Error: {:?}", error),
             Error::SizeNotSupported(size) => write!(f, "Size not supported: {}", size),
             Error::WrongRedStoneMarker(bytes) => {
-                write!(f, "Wrong RedStone marker: {}", bytes.as_hex_str())
+                write!(
+                    f,
+                    "Wrong RedStone marker: {}, expected: {}",
+                    bytes.as_hex_str(),
+                    (&REDSTONE_MARKER[..]).as_hex_str()
+                )
             }
             Error::NonEmptyPayloadRemainder(len) => {
                 write!(f, "Non empty payload len remainder: {}", len)
@@ -201,7 +348,7 @@ impl Display for Error {
                 value, data_package_index
             ),
             Error::ReocuringFeedId(feed) => {
-                write!(f, "Reocuring FeedId: {feed:?} in data points")
+                write!(f, "Reocuring FeedId: {feed} in data points")
             }
             Error::ConfigInsufficientSignerCount(got, expected) => {
                 write!(f, "Wrong configuration signer count, got {got} signers, expected at minimum {expected}")
@@ -231,6 +378,49 @@ impl Display for Error {
                 "Timestamp {:?} is not equal to the first on {:?} in the payload.",
                 outstandig, first
             ),
+            Error::InvalidHex(snippet) => write!(f, "Invalid hex string: {snippet}"),
+            Error::InvalidSignerAddressLength(len) => write!(
+                f,
+                "Wrong signer address length: {len} bytes, expected {SIGNER_ADDRESS_BS}"
+            ),
+            Error::InvalidFeedIdLength(len) => write!(
+                f,
+                "Wrong feed id length: {len} bytes, expected 1 to {VALUE_SIZE}"
+            ),
+            Error::DataStaleness(data_time, time_now) => write!(
+                f,
+                "Data timestamp: {data_time:?} is stale at current time: {time_now:?}"
+            ),
+            Error::FeedDataStaleness(feed_id, data_time, time_now) => write!(
+                f,
+                "Data timestamp: {data_time:?} of {feed_id} is stale at current time: {time_now:?}"
+            ),
+            Error::ValueOutOfBounds(feed_id, value) => write!(
+                f,
+                "Aggregated value: {} of {feed_id} is out of the configured bounds",
+                value.to_u256()
+            ),
+            Error::BufferOverflow(required, available) => write!(
+                f,
+                "Buffer too small: {required} bytes required, {available} available"
+            ),
+            Error::ConfigInvalidFeedId(feed_id) => {
+                write!(
+                    f,
+                    "Wrong configuration, feed id {} is missing on the feed_ids list",
+                    feed_id.as_hex_str()
+                )
+            }
+            Error::EmptyPayload => write!(f, "Empty payload"),
+            Error::ConfigMalformedBytes(offset) => {
+                write!(f, "Malformed configuration bytes at offset {offset}")
+            }
+            Error::ConfigExceededFeedCount(got, allowed) => {
+                write!(
+                    f,
+                    "Wrong configuration feed count, got {got} feeds, allowed maximum is {allowed}"
+                )
+            }
             Error::DataTimestampMustBeGreaterThanBefore(current, before) => {
                 write!(
                     f,
@@ -246,3 +436,200 @@ impl Display for Error {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    use crate::{
+        network::error::{ContractErrorContent, Error},
+        CryptoError, FeedId, SignerAddress, Value,
+    };
+
+    /// Doesn't compile when a variant is added, so its code gets pinned in the `pinned_codes`.
+    fn variant_name(error: &Error) -> &'static str {
+        match error {
+            Error::ContractError(_) => "ContractError",
+            Error::NumberOverflow(_) => "NumberOverflow",
+            Error::ArrayIsEmpty => "ArrayIsEmpty",
+            Error::CryptographicError(_) => "CryptographicError",
+            Error::SizeNotSupported(_) => "SizeNotSupported",
+            Error::WrongRedStoneMarker(_) => "WrongRedStoneMarker",
+            Error::NonEmptyPayloadRemainder(_) => "NonEmptyPayloadRemainder",
+            Error::InsufficientSignerCount(_, _, _) => "InsufficientSignerCount",
+            Error::TimestampTooOld(_, _) => "TimestampTooOld",
+            Error::TimestampTooFuture(_, _) => "TimestampTooFuture",
+            Error::ReocuringFeedId(_) => "ReocuringFeedId",
+            Error::ConfigInsufficientSignerCount(_, _) => "ConfigInsufficientSignerCount",
+            Error::ConfigExceededSignerCount(_, _) => "ConfigExceededSignerCount",
+            Error::ConfigReocuringSigner(_) => "ConfigReocuringSigner",
+            Error::ConfigEmptyFeedIds => "ConfigEmptyFeedIds",
+            Error::ConfigReocuringFeedId(_) => "ConfigReocuringFeedId",
+            Error::TimestampDifferentThanOthers(_, _) => "TimestampDifferentThanOthers",
+            Error::InvalidHex(_) => "InvalidHex",
+            Error::InvalidSignerAddressLength(_) => "InvalidSignerAddressLength",
+            Error::InvalidFeedIdLength(_) => "InvalidFeedIdLength",
+            Error::DataStaleness(_, _) => "DataStaleness",
+            Error::FeedDataStaleness(_, _, _) => "FeedDataStaleness",
+            Error::ValueOutOfBounds(_, _) => "ValueOutOfBounds",
+            Error::BufferOverflow(_, _) => "BufferOverflow",
+            Error::ConfigInvalidFeedId(_) => "ConfigInvalidFeedId",
+            Error::EmptyPayload => "EmptyPayload",
+            Error::ConfigMalformedBytes(_) => "ConfigMalformedBytes",
+            Error::ConfigExceededFeedCount(_, _) => "ConfigExceededFeedCount",
+            Error::DataTimestampMustBeGreaterThanBefore(_, _) => {
+                "DataTimestampMustBeGreaterThanBefore"
+            }
+            Error::CurrentTimestampMustBeGreaterThanLatestUpdateTimestamp(_, _) => {
+                "CurrentTimestampMustBeGreaterThanLatestUpdateTimestamp"
+            }
+        }
+    }
+
+    fn pinned_codes() -> Vec<(Error, u16)> {
+        let feed_id = FeedId::from(b"ETH".to_vec());
+        let signer = SignerAddress::from(vec![0x12; 20]);
+
+        vec![
+            (
+                Error::ContractError(ContractErrorContent {
+                    code: 42,
+                    msg: "msg".into(),
+                }),
+                42,
+            ),
+            (Error::NumberOverflow(Value::from(1u8)), 509),
+            (Error::ArrayIsEmpty, 510),
+            (Error::WrongRedStoneMarker(vec![0xff]), 511),
+            (Error::NonEmptyPayloadRemainder(7), 512),
+            (Error::ReocuringFeedId(feed_id), 513),
+            (Error::ConfigInsufficientSignerCount(1, 2), 514),
+            (Error::ConfigExceededSignerCount(300, 255), 515),
+            (Error::ConfigReocuringSigner(signer), 516),
+            (Error::ConfigEmptyFeedIds, 517),
+            (Error::ConfigReocuringFeedId(feed_id), 518),
+            (Error::TimestampDifferentThanOthers(1.into(), 2.into()), 519),
+            (Error::InvalidHex("zz".into()), 520),
+            (Error::InvalidSignerAddressLength(19), 521),
+            (Error::InvalidFeedIdLength(33), 522),
+            (Error::DataStaleness(1.into(), 2.into()), 523),
+            (Error::FeedDataStaleness(feed_id, 1.into(), 2.into()), 524),
+            (Error::ValueOutOfBounds(feed_id, Value::from(1u8)), 525),
+            (Error::BufferOverflow(2, 1), 526),
+            (Error::ConfigInvalidFeedId(feed_id), 527),
+            (Error::EmptyPayload, 528),
+            (Error::ConfigMalformedBytes(3), 529),
+            (Error::ConfigExceededFeedCount(65536, 65535), 530),
+            (Error::SizeNotSupported(0), 600),
+            (Error::SizeNotSupported(32), 632),
+            (
+                Error::CryptographicError(CryptoError::RecoveryByte(74)),
+                774,
+            ),
+            (
+                Error::CryptographicError(CryptoError::Signature(vec![0; 65])),
+                765,
+            ),
+            (Error::CryptographicError(CryptoError::RecoverPreHash), 700),
+            (
+                Error::CryptographicError(CryptoError::RecoveryByte(255)),
+                790,
+            ),
+            (
+                Error::CryptographicError(CryptoError::Signature(vec![0; 130])),
+                790,
+            ),
+            (Error::CryptographicError(CryptoError::SignatureRZero), 791),
+            (Error::CryptographicError(CryptoError::SignatureSZero), 792),
+            (
+                Error::CryptographicError(CryptoError::SignatureRTooLarge),
+                793,
+            ),
+            (Error::CryptographicError(CryptoError::SignatureHighS), 794),
+            (
+                Error::CryptographicError(CryptoError::PublicKey(vec![0; 64])),
+                795,
+            ),
+            (Error::TimestampTooOld(3, 1.into()), 1003),
+            (Error::TimestampTooFuture(3, 1.into()), 1053),
+            (
+                Error::DataTimestampMustBeGreaterThanBefore(1.into(), 2.into()),
+                1101,
+            ),
+            (
+                Error::CurrentTimestampMustBeGreaterThanLatestUpdateTimestamp(1.into(), 2.into()),
+                1102,
+            ),
+            (Error::InsufficientSignerCount(0, 1, feed_id), 2001),
+            (Error::InsufficientSignerCount(2, 3, feed_id), 2023),
+        ]
+    }
+
+    #[test]
+    fn test_error_codes_are_pinned() {
+        for (error, code) in pinned_codes() {
+            assert_eq!(error.code(), code, "{:?}", error);
+        }
+    }
+
+    #[test]
+    fn test_from_code() {
+        for (error, code) in pinned_codes() {
+            assert_eq!(Error::from_code(code), Some(variant_name(&error)), "{code}");
+        }
+    }
+
+    #[test]
+    fn test_from_unknown_code() {
+        for code in [256, 508, 531, 800, 999, 1100, 1103, 1999] {
+            assert_eq!(Error::from_code(code), None, "{code}");
+        }
+    }
+
+    #[test]
+    fn test_crypto_error_codes_stay_in_their_band() {
+        let crypto_errors = (0..=u8::MAX)
+            .map(CryptoError::RecoveryByte)
+            .chain((0..=1024).map(|len| CryptoError::Signature(vec![0; len])))
+            .chain([
+                CryptoError::RecoverPreHash,
+                CryptoError::SignatureRZero,
+                CryptoError::SignatureSZero,
+                CryptoError::SignatureRTooLarge,
+                CryptoError::SignatureHighS,
+                CryptoError::PublicKey(vec![0; 33]),
+            ]);
+
+        for crypto_error in crypto_errors {
+            let code = Error::CryptographicError(crypto_error).code();
+
+            assert!((700..=799).contains(&code), "{code}");
+            assert_eq!(Error::from_code(code), Some("CryptographicError"));
+        }
+    }
+
+    #[test]
+    fn test_fixed_crypto_error_codes_dont_overlap_parametrized_ones() {
+        let parametrized_codes: Vec<_> = (0..=u8::MAX)
+            .map(CryptoError::RecoveryByte)
+            .chain((0..=1024).map(|len| CryptoError::Signature(vec![0; len])))
+            .map(|crypto_error| crypto_error.code())
+            .collect();
+
+        for crypto_error in [
+            CryptoError::SignatureRZero,
+            CryptoError::SignatureSZero,
+            CryptoError::SignatureRTooLarge,
+            CryptoError::SignatureHighS,
+            CryptoError::PublicKey(vec![0; 33]),
+        ] {
+            assert!(
+                !parametrized_codes.contains(&crypto_error.code()),
+                "{crypto_error:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use crate::{FeedId, SignerAddress, Value};
+
+/// Level of the logged event.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum LogLevel {
+    Debug,
+    Info,
+    Warn,
+}
+
+/// Structured event emitted during the payload processing, see [`crate::network::Environment::log`].
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum LogEvent {
+    /// The payload has been decoded into `package_count` data packages.
+    PayloadDecoded { package_count: usize },
+    /// The signer of a data package has been recovered.
+    SignerRecovered { signer: SignerAddress },
+    /// The value of the feed has been aggregated.
+    ValueAggregated { feed_id: FeedId, value: Value },
+}
+
+#[cfg(feature = "tracing")]
+impl LogEvent {
+    /// Emits the event as a `tracing` event of the corresponding level.
+    pub(crate) fn trace(&self, level: LogLevel) {
+        use crate::network::as_str::AsHexStr;
+
+        macro_rules! event {
+            ($($fields:tt)*) => {
+                match level {
+                    LogLevel::Debug => tracing::debug!($($fields)*),
+                    LogLevel::Info => tracing::info!($($fields)*),
+                    LogLevel::Warn => tracing::warn!($($fields)*),
+                }
+            };
+        }
+
+        match self {
+            LogEvent::PayloadDecoded { package_count } => {
+                event!(package_count, "payload decoded")
+            }
+            LogEvent::SignerRecovered { signer } => {
+                event!(signer = %signer.as_hex_str(), "signer recovered")
+            }
+            LogEvent::ValueAggregated { feed_id, value } => {
+                event!(feed_id = %feed_id, value = %value.to_u256(), "value aggregated")
+            }
+        }
+    }
+}
@@ -1,11 +1,31 @@
 pub mod as_str;
 pub mod error;
+mod log;
+
 use alloc::string::String;
 
+pub use log::{LogEvent, LogLevel};
+
 /// Environment in which the code executes.
 pub trait Environment {
     /// Environment specific print function.
     fn print<F: FnOnce() -> String>(print_content: F);
+
+    /// Environment specific structured logging function.
+    ///
+    /// Does nothing by default, so the structured logging is opt-in:
+    /// the existing environments keep only their `print` output, without the per-event noise or on-chain cost.
+    fn log(_level: LogLevel, _event: &LogEvent) {}
+}
+
+/// Logs the event with the `Environment`, emitting it also as a `tracing` event with the `tracing` feature,
+/// so the `Environment` implementations don't need to be changed for it.
+#[inline]
+pub(crate) fn log_event<Env: Environment>(level: LogLevel, event: &LogEvent) {
+    #[cfg(feature = "tracing")]
+    event.trace(level);
+
+    Env::log(level, event);
 }
 
 /// Default and standard implementation of the `Environmet` trait.
@@ -18,3 +38,67 @@ impl Environment for StdEnv {
         println!("{}", _print_content())
     }
 }
+
+/// Silent implementation of the `Environmet` trait.
+/// Doesn't print anything, the print content isn't even evaluated.
+pub struct Dummy;
+
+impl Environment for Dummy {
+    fn print<F: FnOnce() -> String>(_print_content: F) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+    use core::{
+        cell::Cell,
+        sync::atomic::{AtomicBool, Ordering},
+    };
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    use crate::{
+        network::{Dummy, Environment, LogEvent, LogLevel},
+        FeedId,
+    };
+
+    #[test]
+    fn test_dummy_print_does_nothing() {
+        let evaluated = Cell::new(false);
+
+        Dummy::print(|| {
+            evaluated.set(true);
+            String::from("not printed")
+        });
+
+        assert!(!evaluated.get());
+    }
+
+    #[test]
+    fn test_default_log_does_not_print() {
+        struct PrintingEnv;
+
+        static PRINTED: AtomicBool = AtomicBool::new(false);
+
+        impl Environment for PrintingEnv {
+            fn print<F: FnOnce() -> String>(_print_content: F) {
+                PRINTED.store(true, Ordering::Relaxed);
+            }
+        }
+
+        PrintingEnv::log(
+            LogLevel::Debug,
+            &LogEvent::PayloadDecoded { package_count: 1 },
+        );
+        PrintingEnv::log(
+            LogLevel::Info,
+            &LogEvent::ValueAggregated {
+                feed_id: FeedId::from([0; 32]),
+                value: 1u8.into(),
+            },
+        );
+
+        assert!(!PRINTED.load(Ordering::Relaxed));
+    }
+}
@@ -3,10 +3,19 @@ pub const DATA_PACKAGES_COUNT_BS: usize = 2;
 pub const DATA_POINT_COUNT_MAX_VALUE: usize = u16::MAX as usize; // 0xFFFF
 pub const DATA_POINTS_COUNT_BS: usize = 3;
 pub const SIGNATURE_BS: usize = 65;
+pub const SIGNER_ADDRESS_BS: usize = 20;
 pub const DATA_POINT_VALUE_BYTE_SIZE_BS: usize = 4;
+/// The value size of the data package flagging the variable value sizes of its data points.
+/// Each data point of such a package is followed by its own value size, of `DATA_POINT_VALUE_BYTE_SIZE_BS` bytes.
+pub const VARIABLE_VALUE_SIZE: usize = 0xFFFF_FFFF;
 pub const DATA_FEED_ID_BS: usize = 32;
 pub const TIMESTAMP_BS: usize = 6;
+/// The default maximum delay of the package against the block timestamp, 15 minutes.
+/// Can be overridden per `Config`, see the `Config::with_default_windows`.
 pub const MAX_TIMESTAMP_DELAY_MS: u64 = 15 * 60 * 1000; // 15 minutes in milliseconds
+/// The default maximum time the package is created ahead of the block timestamp, 3 minutes.
+/// Can be overridden per `Config`, see the `Config::with_default_windows`.
 pub const MAX_TIMESTAMP_AHEAD_MS: u64 = 3 * 60 * 1000; // 3 minutes in milliseconds
+pub const REDSTONE_PRICE_DECIMALS: u8 = 8;
 pub const REDSTONE_MARKER_BS: usize = 9;
 pub const REDSTONE_MARKER: [u8; 9] = [0, 0, 2, 237, 87, 1, 30, 0, 0]; // 0x000002ed57011e0000
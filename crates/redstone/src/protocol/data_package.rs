@@ -1,14 +1,85 @@
 use alloc::vec::Vec;
 use core::fmt::{Debug, Formatter};
 
-use crate::{protocol::data_point::DataPoint, SignerAddress, TimestampMillis};
-#[derive(Clone, PartialEq, Eq)]
+use derive_getters::Getters;
+
+use crate::{
+    crypto::Crypto,
+    network::{error::Error, Dummy},
+    protocol::{data_point::DataPoint, PayloadDecoder},
+    utils::ensure::ensure,
+    SignerAddress, TimestampMillis,
+};
+#[cfg(feature = "signatures")]
+use crate::{protocol::constants::SIGNATURE_BS, Bytes};
+
+/// Data package of the RedStone payload: the data points signed together by a single signer.
+///
+/// The data packages are compared by their decoded content, the signer, the timestamp and the data points,
+/// leaving out the raw signature and signable bytes retained with the `signatures` feature.
+#[derive(Clone, Getters)]
 pub struct DataPackage {
+    /// Address of the signer, recovered from the data package signature.
     pub(crate) signer_address: SignerAddress,
+    /// Timestamp of the data package.
     pub(crate) timestamp: TimestampMillis,
+    /// Data points of the data package.
     pub(crate) data_points: Vec<DataPoint>,
+    /// The raw 65-byte signature of the data package, the signer address was recovered from.
+    #[cfg(feature = "signatures")]
+    pub(crate) signature: Bytes,
+    /// The raw signable bytes of the data package, hashed for the signature verification:
+    /// the data points followed by the timestamp, the value size and the data point count.
+    #[cfg(feature = "signatures")]
+    #[getter(skip)]
+    pub(crate) signable_bytes: Bytes,
 }
 
+impl DataPackage {
+    /// Decodes exactly one data package from the `bytes`, without the payload metadata and the RedStone marker,
+    /// recovering its signer with the `C`.
+    ///
+    /// Fails with `Error::NonEmptyPayloadRemainder` when the bytes don't end with the data package.
+    pub fn from_bytes<C: Crypto>(bytes: &[u8]) -> Result<DataPackage, Error> {
+        let mut bytes = bytes.to_vec();
+        let data_package = PayloadDecoder::<Dummy, C>::trim_data_package(&mut bytes)?;
+
+        ensure(
+            bytes.is_empty(),
+            Error::NonEmptyPayloadRemainder(bytes.len()),
+        )?;
+
+        Ok(data_package)
+    }
+}
+
+#[cfg(feature = "signatures")]
+impl DataPackage {
+    /// Returns the recovery id of the signature, normalized to `0` or `1`
+    /// when the recovery byte is given as `27` or `28`.
+    pub fn recovery_id(&self) -> Option<u8> {
+        let recovery_byte = *self.signature.as_slice().get(SIGNATURE_BS - 1)?;
+
+        Some(recovery_byte - (if recovery_byte >= 27 { 27 } else { 0 }))
+    }
+
+    /// Returns the exact bytes the signer address was recovered from along with the `signature`,
+    /// so the signature can be verified independently.
+    pub fn signable_bytes(&self) -> &[u8] {
+        self.signable_bytes.as_slice()
+    }
+}
+
+impl PartialEq for DataPackage {
+    fn eq(&self, other: &Self) -> bool {
+        self.signer_address == other.signer_address
+            && self.timestamp == other.timestamp
+            && self.data_points == other.data_points
+    }
+}
+
+impl Eq for DataPackage {}
+
 impl Debug for DataPackage {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         write!(
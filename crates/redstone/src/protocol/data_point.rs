@@ -1,14 +1,19 @@
 use core::fmt::{Debug, Formatter};
 
+use derive_getters::Getters;
+
 use crate::{
     network::as_str::{AsAsciiStr, AsHexStr},
     types::Value,
     FeedId,
 };
 
-#[derive(Clone, PartialEq, Eq)]
+/// Single value of the data feed, as a part of a data package.
+#[derive(Clone, PartialEq, Eq, Getters)]
 pub struct DataPoint {
+    /// Id of the data feed.
     pub(crate) feed_id: FeedId,
+    /// Value of the data feed.
     pub(crate) value: Value,
 }
 
@@ -3,29 +3,65 @@ use alloc::vec::Vec;
 use crate::{
     network::error::Error,
     protocol::constants::{REDSTONE_MARKER, REDSTONE_MARKER_BS},
-    utils::trim::Trim,
+    utils::ensure::ensure,
 };
 
-pub fn trim_redstone_marker(payload: &mut Vec<u8>) -> Result<(), Error> {
-    let marker: Vec<u8> = payload.trim_end(REDSTONE_MARKER_BS);
+/// Reads the RedStone marker ending the payload, without trimming it,
+/// so the payload format can be checked before it's decoded.
+///
+/// Returns the decoded marker, or fails with `Error::WrongRedStoneMarker` carrying the actual ending bytes
+/// when they differ from the `REDSTONE_MARKER`.
+/// Fails with `Error::EmptyPayload` for the empty payload or the one consisting of the marker only.
+///
+/// ```
+/// use redstone::{read_redstone_marker, REDSTONE_MARKER};
+///
+/// let mut payload = vec![1, 2, 3];
+/// payload.extend_from_slice(&REDSTONE_MARKER);
+///
+/// assert_eq!(read_redstone_marker(&payload), Ok(REDSTONE_MARKER));
+/// assert!(read_redstone_marker(&payload[..payload.len() - 1]).is_err());
+/// ```
+pub fn read_redstone_marker(payload: &[u8]) -> Result<[u8; REDSTONE_MARKER_BS], Error> {
+    ensure(!payload.is_empty(), Error::EmptyPayload)?;
 
-    if marker != REDSTONE_MARKER {
-        return Err(Error::WrongRedStoneMarker(marker));
+    let marker = &payload[payload.len().saturating_sub(REDSTONE_MARKER_BS)..];
+
+    if marker != REDSTONE_MARKER.as_slice() {
+        return Err(Error::WrongRedStoneMarker(marker.to_vec()));
     }
 
-    Ok(())
+    ensure(payload.len() > REDSTONE_MARKER_BS, Error::EmptyPayload)?;
+
+    Ok(REDSTONE_MARKER)
+}
+
+/// Trims the RedStone marker ending the payload and returns it, see [`read_redstone_marker`].
+///
+/// Fails with `Error::EmptyPayload` for the empty payload or the one consisting of the marker only,
+/// before any of its parts is read.
+pub fn trim_redstone_marker(payload: &mut Vec<u8>) -> Result<[u8; REDSTONE_MARKER_BS], Error> {
+    let marker = read_redstone_marker(payload)?;
+    payload.truncate(payload.len() - REDSTONE_MARKER_BS);
+
+    Ok(marker)
 }
 
 #[cfg(feature = "helpers")]
 #[cfg(test)]
 mod tests {
+    use alloc::string::ToString;
+
     #[cfg(target_arch = "wasm32")]
     use wasm_bindgen_test::wasm_bindgen_test as test;
 
     use crate::{
         helpers::hex::hex_to_bytes,
         network::error::Error,
-        protocol::{constants::REDSTONE_MARKER_BS, marker::trim_redstone_marker},
+        protocol::{
+            constants::{REDSTONE_MARKER, REDSTONE_MARKER_BS},
+            marker::{read_redstone_marker, trim_redstone_marker},
+        },
     };
 
     const PAYLOAD_TAIL: &str = "1c000f000000000002ed57011e0000";
@@ -33,7 +69,9 @@ mod tests {
     #[test]
     fn test_trim_redstone_marker() {
         let mut bytes = hex_to_bytes(PAYLOAD_TAIL.into());
-        trim_redstone_marker(&mut bytes).unwrap();
+        let marker = trim_redstone_marker(&mut bytes).unwrap();
+
+        assert_eq!(marker, REDSTONE_MARKER);
 
         assert_eq!(
             bytes,
@@ -41,6 +79,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_read_redstone_marker_doesnt_trim() {
+        let bytes = hex_to_bytes(PAYLOAD_TAIL.into());
+
+        assert_eq!(read_redstone_marker(&bytes), Ok(REDSTONE_MARKER));
+        assert_eq!(bytes, hex_to_bytes(PAYLOAD_TAIL.into()));
+    }
+
+    #[test]
+    fn test_trim_redstone_marker_empty() {
+        assert_eq!(trim_redstone_marker(&mut vec![]), Err(Error::EmptyPayload));
+        assert_eq!(
+            trim_redstone_marker(&mut REDSTONE_MARKER.to_vec()),
+            Err(Error::EmptyPayload)
+        );
+    }
+
     #[test]
     fn test_trim_redstone_marker_wrong() {
         let res = trim_redstone_marker(&mut hex_to_bytes(PAYLOAD_TAIL.replace('1', "2")));
@@ -52,6 +107,32 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_trim_redstone_marker_flipped_byte() {
+        let mut bytes = hex_to_bytes(PAYLOAD_TAIL.into());
+        let index = bytes.len() - 4;
+        bytes[index] ^= 0x01;
+
+        assert_eq!(
+            read_redstone_marker(&bytes),
+            trim_redstone_marker(&mut bytes.clone())
+        );
+
+        let res = trim_redstone_marker(&mut bytes);
+
+        let Err(error) = res else {
+            panic!("Expected the wrong marker error");
+        };
+        assert_eq!(
+            error,
+            Error::WrongRedStoneMarker(vec![0, 0, 2, 237, 87, 0, 30, 0, 0])
+        );
+        assert_eq!(
+            error.to_string(),
+            "Wrong RedStone marker: 000002ed57001e0000, expected: 000002ed57011e0000"
+        );
+    }
+
     #[test]
     fn test_trim_redstone_marker_wrong_ending() {
         let res = trim_redstone_marker(&mut hex_to_bytes(
@@ -6,4 +6,5 @@ pub mod payload;
 
 mod payload_decoder;
 
+pub use marker::read_redstone_marker;
 pub use payload_decoder::PayloadDecoder;
@@ -1,15 +1,79 @@
+use alloc::{collections::BTreeSet, vec::Vec};
+
+use derive_getters::Getters;
+
 use crate::{
-    core::validator::Validator, network::error::Error, protocol::data_package::DataPackage,
-    TimestampMillis,
+    core::validator::Validator, network::error::Error, protocol::data_package::DataPackage, FeedId,
+    SignerAddress, TimestampMillis,
 };
-use alloc::vec::Vec;
 
-#[derive(Clone, Debug)]
+/// Decoded RedStone payload, see [`crate::core::decode_payload`].
+///
+/// The decoded structure is exposed by the read-only getters:
+///
+/// ```
+/// use redstone::Payload;
+///
+/// fn print_values(payload: &Payload) {
+///     for package in payload.data_packages() {
+///         for data_point in package.data_points() {
+///             println!(
+///                 "{:?} signed {} = {} at {:?}",
+///                 package.signer_address(),
+///                 data_point.feed_id(),
+///                 data_point.value().to_u256(),
+///                 package.timestamp(),
+///             );
+///         }
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Getters)]
 pub struct Payload {
+    /// Data packages of the payload, in the decoding order.
     pub(crate) data_packages: Vec<DataPackage>,
 }
 
 impl Payload {
+    /// Returns the number of data points in all the data packages.
+    pub fn total_data_points(&self) -> usize {
+        self.data_packages
+            .iter()
+            .map(|package| package.data_points.len())
+            .sum()
+    }
+
+    /// Returns the distinct signers of the data packages.
+    pub fn signer_set(&self) -> BTreeSet<SignerAddress> {
+        self.data_packages
+            .iter()
+            .map(|package| package.signer_address)
+            .collect()
+    }
+
+    /// Returns the distinct feed ids of the data points in all the data packages.
+    pub fn feed_set(&self) -> BTreeSet<FeedId> {
+        self.data_packages
+            .iter()
+            .flat_map(|package| package.data_points.iter())
+            .map(|data_point| data_point.feed_id)
+            .collect()
+    }
+
+    /// Returns the payload in the deterministic order, for comparing the payloads regardless of their encoding order:
+    /// the data packages ordered by the signer address and the timestamp, the data points of each by the feed id.
+    pub fn sorted(mut self) -> Self {
+        for package in self.data_packages.iter_mut() {
+            package
+                .data_points
+                .sort_by_key(|data_point| data_point.feed_id);
+        }
+        self.data_packages
+            .sort_by_key(|package| (package.signer_address, package.timestamp));
+
+        self
+    }
+
     pub fn get_validated_timestamp(
         &self,
         validator: &impl Validator,
@@ -39,8 +103,23 @@ impl Payload {
 
 #[cfg(test)]
 mod tests {
+    use alloc::vec::Vec;
+
     use super::Payload;
-    use crate::{core::config::Config, network::error::Error, protocol::data_package::DataPackage};
+    #[cfg(feature = "default-crypto")]
+    use crate::{
+        core::test_helpers::SAMPLE_PAYLOAD_SIGNERS, default_ext::DefaultCrypto,
+        helpers::hex::sample_payload_bytes, network::StdEnv, protocol::PayloadDecoder,
+    };
+    use crate::{
+        core::{
+            config::Config,
+            test_helpers::{from_ticker, TEST_SIGNER_ADDRESS_2},
+        },
+        helpers::hex::make_signer_address,
+        network::error::Error,
+        protocol::data_package::DataPackage,
+    };
 
     const TEST_BLOCK_TIMESTAMP: u64 = 2000000000000;
     const TEST_SIGNER_ADDRESS_1: &str = "1ea62d73edF8ac05dfcea1a34b9796e937a29eFF";
@@ -116,6 +195,73 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_size_accounting() {
+        let data_packages = vec![
+            DataPackage::test_multi_data_point(
+                vec![(BTC, 30), (ETH, 11)],
+                TEST_SIGNER_ADDRESS_1,
+                (TEST_BLOCK_TIMESTAMP).into(),
+            ),
+            DataPackage::test_single_data_point(ETH, 10, TEST_SIGNER_ADDRESS_2, None),
+        ];
+        let payload = Payload { data_packages };
+
+        assert_eq!(payload.total_data_points(), 3);
+        assert_eq!(
+            payload.signer_set(),
+            [TEST_SIGNER_ADDRESS_1, TEST_SIGNER_ADDRESS_2]
+                .map(make_signer_address)
+                .into()
+        );
+        assert_eq!(payload.feed_set(), [ETH, BTC].map(from_ticker).into());
+    }
+
+    #[cfg(feature = "default-crypto")]
+    #[test]
+    fn test_size_accounting_of_sample_payload() {
+        let payload =
+            PayloadDecoder::<StdEnv, DefaultCrypto>::make_payload(&mut sample_payload_bytes())
+                .unwrap();
+
+        assert_eq!(payload.total_data_points(), 15);
+        assert_eq!(
+            payload.signer_set(),
+            SAMPLE_PAYLOAD_SIGNERS.map(make_signer_address).into()
+        );
+        assert_eq!(
+            payload.feed_set(),
+            [ETH, BTC, "AVAX"].map(from_ticker).into()
+        );
+    }
+
+    #[test]
+    fn test_sorted() {
+        let make_payload = |packages: Vec<(Vec<(&str, u128)>, &str)>| Payload {
+            data_packages: packages
+                .into_iter()
+                .map(|(data_points, signer)| {
+                    DataPackage::test_multi_data_point(
+                        data_points,
+                        signer,
+                        (TEST_BLOCK_TIMESTAMP).into(),
+                    )
+                })
+                .collect(),
+        };
+        let payload = make_payload(vec![
+            (vec![(ETH, 10), (BTC, 31)], TEST_SIGNER_ADDRESS_2),
+            (vec![(BTC, 30), (ETH, 11)], TEST_SIGNER_ADDRESS_1),
+        ]);
+        let reordered = make_payload(vec![
+            (vec![(ETH, 11), (BTC, 30)], TEST_SIGNER_ADDRESS_1),
+            (vec![(BTC, 31), (ETH, 10)], TEST_SIGNER_ADDRESS_2),
+        ]);
+
+        assert_ne!(payload, reordered);
+        assert_eq!(payload.sorted(), reordered.sorted());
+    }
+
     #[test]
     fn test_validate_all_timestamps_in_payload_is_empty() {
         let config = Config::test_with_signer_count_threshold_or_default(None);
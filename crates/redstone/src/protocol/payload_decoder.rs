@@ -1,52 +1,242 @@
 use alloc::vec::Vec;
 use core::marker::PhantomData;
 
+#[cfg(feature = "async-crypto")]
+use crate::crypto::AsyncCrypto;
 use crate::{
     crypto::Crypto,
-    network::{error::Error, Environment},
+    network::{error::Error, log_event, Environment, LogEvent, LogLevel},
     protocol::{
         constants::{
             DATA_FEED_ID_BS, DATA_PACKAGES_COUNT_BS, DATA_POINTS_COUNT_BS,
             DATA_POINT_COUNT_MAX_VALUE, DATA_POINT_VALUE_BYTE_SIZE_BS, SIGNATURE_BS, TIMESTAMP_BS,
-            UNSIGNED_METADATA_BYTE_SIZE_BS,
+            UNSIGNED_METADATA_BYTE_SIZE_BS, VARIABLE_VALUE_SIZE,
         },
         data_package::DataPackage,
         data_point::DataPoint,
         marker::trim_redstone_marker,
         payload::Payload,
     },
-    utils::trim::{Trim, TryTrim},
-    TimestampMillis,
+    types::VALUE_SIZE,
+    utils::{
+        ensure::{ensure, ResultExt},
+        trim::{Trim, TryTrim},
+    },
+    FeedId, SignerAddress, TimestampMillis,
 };
 
-pub struct PayloadDecoder<Env: Environment, C: Crypto>(PhantomData<(Env, C)>);
+const DATA_PACKAGE_HEADER_BS: usize =
+    DATA_POINTS_COUNT_BS + DATA_POINT_VALUE_BYTE_SIZE_BS + TIMESTAMP_BS;
+
+pub struct PayloadDecoder<Env: Environment, C>(PhantomData<(Env, C)>);
+
+/// The part of the data package trimmed before the signer is recovered.
+///
+/// The signable bytes are owned by default, or are the length of the bytes copied to a scratch buffer.
+struct DataPackageHeader<S = Vec<u8>> {
+    signature: Vec<u8>,
+    signable_bytes: S,
+    #[cfg(feature = "signatures")]
+    retained_signable_bytes: Vec<u8>,
+    data_point_count: usize,
+    value_size: usize,
+    timestamp: u64,
+}
 
 impl<Env: Environment, C: Crypto> PayloadDecoder<Env, C> {
+    /// Decodes the payload ending with the RedStone marker.
+    ///
+    /// Fails with `Error::WrongRedStoneMarker` when the marker is missing,
+    /// use the `make_payload_unmarked` for the payloads passed without the marker.
     pub fn make_payload(payload_bytes: &mut Vec<u8>) -> Result<Payload, Error> {
         trim_redstone_marker(payload_bytes)?;
+
+        Self::make_payload_unmarked(payload_bytes)
+    }
+
+    /// Decodes the payload body, passed without the RedStone marker appended.
+    pub fn make_payload_unmarked(payload_bytes: &mut Vec<u8>) -> Result<Payload, Error> {
         let payload = Self::trim_payload(payload_bytes)?;
 
-        if !payload_bytes.is_empty() {
-            return Err(Error::NonEmptyPayloadRemainder(payload_bytes.len()));
-        }
+        ensure(
+            payload_bytes.is_empty(),
+            Error::NonEmptyPayloadRemainder(payload_bytes.len()),
+        )?;
 
         Ok(payload)
     }
 
-    fn trim_payload(payload: &mut Vec<u8>) -> Result<Payload, Error> {
-        let data_package_count = Self::trim_metadata(payload)?;
-        let data_packages = Self::trim_data_packages(payload, data_package_count)?;
+    /// Variant of the `make_payload` trimming the data packages and recovering their signers sequentially,
+    /// as without the `rayon` feature, e.g. for comparing both in the benchmarks.
+    #[cfg(feature = "rayon")]
+    pub fn make_payload_sequential(payload_bytes: &mut Vec<u8>) -> Result<Payload, Error> {
+        trim_redstone_marker(payload_bytes)?;
+        let data_package_count = Self::trim_metadata(payload_bytes)?;
+        let data_packages = Self::trim_data_packages(payload_bytes, data_package_count)?;
+
+        ensure(
+            payload_bytes.is_empty(),
+            Error::NonEmptyPayloadRemainder(payload_bytes.len()),
+        )?;
 
         Ok(Payload { data_packages })
     }
 
-    fn trim_metadata(payload: &mut Vec<u8>) -> Result<usize, Error> {
-        let unsigned_metadata_size = payload.try_trim_end(UNSIGNED_METADATA_BYTE_SIZE_BS)?;
-        let _: Vec<u8> = payload.trim_end(unsigned_metadata_size);
+    /// Decodes one or more marked payloads concatenated in the `payload_bytes`.
+    ///
+    /// The payloads are trimmed from the end until the bytes are empty, and returned in their order in the bytes.
+    /// The bytes left before a payload which don't form a full payload fail the decoding,
+    /// typically with `Error::WrongRedStoneMarker`.
+    pub fn make_payloads(payload_bytes: &mut Vec<u8>) -> Result<Vec<Payload>, Error> {
+        let mut payloads = Vec::new();
+
+        loop {
+            trim_redstone_marker(payload_bytes)?;
+            payloads.push(Self::trim_payload(payload_bytes)?);
+
+            if payload_bytes.is_empty() {
+                break;
+            }
+        }
+        payloads.reverse();
 
-        let data_package_count = payload.try_trim_end(DATA_PACKAGES_COUNT_BS)?;
+        Ok(payloads)
+    }
 
-        Ok(data_package_count)
+    /// Variant of the `make_payload` recovering the signers only of the data packages
+    /// containing a data point of any of the `feed_ids`.
+    ///
+    /// The other data packages can't contribute to the values of the `feed_ids`,
+    /// so they are trimmed without the signer recovery and left out of the payload.
+    pub fn make_payload_for_feeds(
+        payload_bytes: &mut Vec<u8>,
+        feed_ids: &[FeedId],
+    ) -> Result<Payload, Error> {
+        trim_redstone_marker(payload_bytes)?;
+        let data_package_count = Self::trim_metadata(payload_bytes)?;
+        let mut data_packages = Vec::with_capacity(data_package_count);
+
+        for _ in 0..data_package_count {
+            let header = Self::trim_data_package_header(payload_bytes)?;
+            let data_points =
+                Self::trim_data_points(payload_bytes, header.data_point_count, header.value_size)?;
+
+            if data_points
+                .iter()
+                .any(|data_point| feed_ids.contains(&data_point.feed_id))
+            {
+                data_packages.push(Self::recover_data_package(header, data_points)?);
+            }
+        }
+
+        ensure(
+            payload_bytes.is_empty(),
+            Error::NonEmptyPayloadRemainder(payload_bytes.len()),
+        )?;
+
+        Ok(Payload { data_packages })
+    }
+
+    /// Variant of the `make_payload` rejecting the data packages with the timestamps
+    /// outside the `valid_timestamp_range`, like the `Config::valid_timestamp_range`,
+    /// before their signers are recovered.
+    ///
+    /// Lets the processors fail early on the stale payloads, instead of recovering
+    /// all the signers and validating the timestamps at the aggregation.
+    ///
+    /// # Errors
+    ///
+    /// `Error::TimestampTooOld` or `Error::TimestampTooFuture` with the index of the first data package
+    /// trimmed from the end of the payload with the timestamp outside the range.
+    pub fn make_payload_in_timestamp_range(
+        payload_bytes: &mut Vec<u8>,
+        (min_timestamp, max_timestamp): (TimestampMillis, TimestampMillis),
+    ) -> Result<Payload, Error> {
+        trim_redstone_marker(payload_bytes)?;
+        let data_package_count = Self::trim_metadata(payload_bytes)?;
+        let mut data_packages = Vec::with_capacity(data_package_count);
+
+        for index in 0..data_package_count {
+            let header = Self::trim_data_package_header(payload_bytes)?;
+            let timestamp = TimestampMillis::from_millis(header.timestamp);
+
+            ensure(
+                timestamp.is_same_or_after(min_timestamp),
+                Error::TimestampTooOld(index, timestamp),
+            )?;
+            ensure(
+                timestamp.is_same_or_before(max_timestamp),
+                Error::TimestampTooFuture(index, timestamp),
+            )?;
+
+            data_packages.push(Self::trim_data_package_with_header(payload_bytes, header)?);
+        }
+
+        ensure(
+            payload_bytes.is_empty(),
+            Error::NonEmptyPayloadRemainder(payload_bytes.len()),
+        )?;
+
+        Ok(Payload { data_packages })
+    }
+
+    /// Variant of the `make_payload` copying the signable bytes of the data packages
+    /// to the caller-provided `scratch` buffer instead of allocating them per data package.
+    ///
+    /// The `scratch` must fit the signable bytes of the largest data package, that is
+    /// `data_point_count * (value_size + 32) + 13` bytes, see [`PayloadDecoder::scratch_size`].
+    /// For example, a data package of 3 data points with 32-byte values needs 205 bytes.
+    /// The data package of variable value sizes needs the `DATA_POINT_VALUE_BYTE_SIZE_BS` more bytes per data point.
+    ///
+    /// # Errors
+    ///
+    /// `Error::BufferOverflow` when the `scratch` is too small for any of the data packages.
+    pub fn make_payload_with_scratch(
+        payload_bytes: &mut Vec<u8>,
+        scratch: &mut [u8],
+    ) -> Result<Payload, Error> {
+        trim_redstone_marker(payload_bytes)?;
+        let data_package_count = Self::trim_metadata(payload_bytes)?;
+        let mut data_packages = Vec::with_capacity(data_package_count);
+
+        for _ in 0..data_package_count {
+            let header = Self::trim_data_package_header_with(payload_bytes, |signable_bytes| {
+                let (len, available) = (signable_bytes.len(), scratch.len());
+                scratch
+                    .get_mut(..len)
+                    .or_error(|| Error::BufferOverflow(len, available))?
+                    .copy_from_slice(signable_bytes);
+
+                Ok(len)
+            })?;
+            let signer_address =
+                C::recover_address(&scratch[..header.signable_bytes], &header.signature)?;
+            log_event::<Env>(
+                LogLevel::Debug,
+                &LogEvent::SignerRecovered {
+                    signer: signer_address,
+                },
+            );
+            let data_package = Self::trim_data_package_body(payload_bytes, header, signer_address)?;
+            data_packages.push(data_package);
+        }
+
+        ensure(
+            payload_bytes.is_empty(),
+            Error::NonEmptyPayloadRemainder(payload_bytes.len()),
+        )?;
+
+        Ok(Payload { data_packages })
+    }
+
+    fn trim_payload(payload: &mut Vec<u8>) -> Result<Payload, Error> {
+        let data_package_count = Self::trim_metadata(payload)?;
+        #[cfg(not(feature = "rayon"))]
+        let data_packages = Self::trim_data_packages(payload, data_package_count)?;
+        #[cfg(feature = "rayon")]
+        let data_packages = Self::trim_data_packages_parallel(payload, data_package_count)?;
+
+        Ok(Payload { data_packages })
     }
 
     fn trim_data_packages(payload: &mut Vec<u8>, count: usize) -> Result<Vec<DataPackage>, Error> {
@@ -60,40 +250,234 @@ impl<Env: Environment, C: Crypto> PayloadDecoder<Env, C> {
         Ok(data_packages)
     }
 
-    fn trim_data_package(payload: &mut Vec<u8>) -> Result<DataPackage, Error> {
-        let signature: Vec<u8> = payload.trim_end(SIGNATURE_BS);
-        let mut tmp = payload.clone();
+    /// Trims all the data packages first and then recovers their signers in parallel.
+    ///
+    /// The data packages are returned in the same order as by the `trim_data_packages`,
+    /// but a malformed data package is reported before any signer recovery error.
+    #[cfg(feature = "rayon")]
+    fn trim_data_packages_parallel(
+        payload: &mut Vec<u8>,
+        count: usize,
+    ) -> Result<Vec<DataPackage>, Error> {
+        use rayon::prelude::*;
+
+        let mut trimmed = Vec::with_capacity(count);
 
-        let data_point_count = payload.try_trim_end(DATA_POINTS_COUNT_BS)?;
-        let value_size = payload.try_trim_end(DATA_POINT_VALUE_BYTE_SIZE_BS)?;
-        let timestamp = payload.try_trim_end(TIMESTAMP_BS)?;
-        let size = data_point_count * (value_size + DATA_FEED_ID_BS)
-            + DATA_POINT_VALUE_BYTE_SIZE_BS
-            + TIMESTAMP_BS
-            + DATA_POINTS_COUNT_BS;
+        for _ in 0..count {
+            let header = Self::trim_data_package_header(payload)?;
+            let data_points =
+                Self::trim_data_points(payload, header.data_point_count, header.value_size)?;
+            trimmed.push((header, data_points));
+        }
 
-        let signable_bytes: Vec<_> = tmp.trim_end(size);
-        let signer_address = C::recover_address(signable_bytes, signature)?;
+        trimmed
+            .into_par_iter()
+            .map(|(header, data_points)| Self::recover_data_package(header, data_points))
+            .collect()
+    }
 
-        let data_points = Self::trim_data_points(payload, data_point_count, value_size)?;
+    fn recover_data_package(
+        header: DataPackageHeader,
+        data_points: Vec<DataPoint>,
+    ) -> Result<DataPackage, Error> {
+        let signer_address = C::recover_address(&header.signable_bytes, &header.signature)?;
+        log_event::<Env>(
+            LogLevel::Debug,
+            &LogEvent::SignerRecovered {
+                signer: signer_address,
+            },
+        );
 
         Ok(DataPackage {
             data_points,
-            timestamp: TimestampMillis::from_millis(timestamp),
+            timestamp: TimestampMillis::from_millis(header.timestamp),
             signer_address,
+            #[cfg(feature = "signatures")]
+            signature: header.signature.into(),
+            #[cfg(feature = "signatures")]
+            signable_bytes: header.retained_signable_bytes.into(),
         })
     }
 
+    pub(crate) fn trim_data_package(payload: &mut Vec<u8>) -> Result<DataPackage, Error> {
+        let header = Self::trim_data_package_header(payload)?;
+
+        Self::trim_data_package_with_header(payload, header)
+    }
+
+    fn trim_data_package_with_header(
+        payload: &mut Vec<u8>,
+        header: DataPackageHeader,
+    ) -> Result<DataPackage, Error> {
+        let signer_address = C::recover_address(&header.signable_bytes, &header.signature)?;
+        log_event::<Env>(
+            LogLevel::Debug,
+            &LogEvent::SignerRecovered {
+                signer: signer_address,
+            },
+        );
+
+        Self::trim_data_package_body(payload, header, signer_address)
+    }
+}
+
+#[cfg(feature = "async-crypto")]
+impl<Env: Environment, C: AsyncCrypto> PayloadDecoder<Env, C> {
+    /// Async variant of the `make_payload`, awaiting the signer recovery of each data package.
+    pub async fn make_payload_async(
+        crypto: &C,
+        payload_bytes: &mut Vec<u8>,
+    ) -> Result<Payload, Error> {
+        trim_redstone_marker(payload_bytes)?;
+        let data_package_count = Self::trim_metadata(payload_bytes)?;
+        let mut data_packages = Vec::with_capacity(data_package_count);
+
+        for _ in 0..data_package_count {
+            let header = Self::trim_data_package_header(payload_bytes)?;
+            let signer_address = crypto
+                .recover_address(&header.signable_bytes, &header.signature)
+                .await?;
+            log_event::<Env>(
+                LogLevel::Debug,
+                &LogEvent::SignerRecovered {
+                    signer: signer_address,
+                },
+            );
+            let data_package = Self::trim_data_package_body(payload_bytes, header, signer_address)?;
+            data_packages.push(data_package);
+        }
+
+        ensure(
+            payload_bytes.is_empty(),
+            Error::NonEmptyPayloadRemainder(payload_bytes.len()),
+        )?;
+
+        Ok(Payload { data_packages })
+    }
+}
+
+impl<Env: Environment, C> PayloadDecoder<Env, C> {
+    /// Returns the size of the scratch buffer needed by the `make_payload_with_scratch`
+    /// for a data package of `data_point_count` data points with values of `value_size` bytes.
+    pub const fn scratch_size(data_point_count: usize, value_size: usize) -> usize {
+        data_point_count * (value_size + DATA_FEED_ID_BS) + DATA_PACKAGE_HEADER_BS
+    }
+
+    fn trim_metadata(payload: &mut Vec<u8>) -> Result<usize, Error> {
+        let unsigned_metadata_size = payload.try_trim_end(UNSIGNED_METADATA_BYTE_SIZE_BS)?;
+        // the inflated size would otherwise swallow the whole payload, leaving no data packages to decode
+        ensure(
+            unsigned_metadata_size <= payload.len(),
+            Error::BufferOverflow(unsigned_metadata_size, payload.len()),
+        )?;
+        let _: Vec<u8> = payload.trim_end(unsigned_metadata_size);
+
+        let data_package_count = payload.try_trim_end(DATA_PACKAGES_COUNT_BS)?;
+
+        Ok(data_package_count)
+    }
+
+    fn trim_data_package_header(payload: &mut Vec<u8>) -> Result<DataPackageHeader, Error> {
+        Self::trim_data_package_header_with(payload, |signable_bytes| Ok(signable_bytes.to_vec()))
+    }
+
+    fn trim_data_package_header_with<S>(
+        payload: &mut Vec<u8>,
+        copy_signable_bytes: impl FnOnce(&[u8]) -> Result<S, Error>,
+    ) -> Result<DataPackageHeader<S>, Error> {
+        let signature: Vec<u8> = payload.trim_end(SIGNATURE_BS);
+
+        // The header fields are trimmed from a copy of the header only,
+        // so the signable bytes can be copied out of the untouched payload afterwards.
+        let header_start = payload.len().saturating_sub(DATA_PACKAGE_HEADER_BS);
+        let mut header_bytes = payload[header_start..].to_vec();
+
+        let data_point_count = header_bytes.try_trim_end(DATA_POINTS_COUNT_BS)?;
+        let value_size = header_bytes.try_trim_end(DATA_POINT_VALUE_BYTE_SIZE_BS)?;
+        let timestamp = header_bytes.try_trim_end(TIMESTAMP_BS)?;
+        let size = Self::data_points_size(&payload[..header_start], data_point_count, value_size)?
+            + DATA_PACKAGE_HEADER_BS;
+
+        let signable_start = payload.len().saturating_sub(size);
+        let signable_bytes = copy_signable_bytes(&payload[signable_start..])?;
+        #[cfg(feature = "signatures")]
+        let retained_signable_bytes = payload[signable_start..].to_vec();
+        payload.truncate(header_start);
+
+        Ok(DataPackageHeader {
+            signature,
+            signable_bytes,
+            #[cfg(feature = "signatures")]
+            retained_signable_bytes,
+            data_point_count,
+            value_size,
+            timestamp,
+        })
+    }
+
+    fn trim_data_package_body<S>(
+        payload: &mut Vec<u8>,
+        header: DataPackageHeader<S>,
+        signer_address: SignerAddress,
+    ) -> Result<DataPackage, Error> {
+        let data_points =
+            Self::trim_data_points(payload, header.data_point_count, header.value_size)?;
+
+        Ok(DataPackage {
+            data_points,
+            timestamp: TimestampMillis::from_millis(header.timestamp),
+            signer_address,
+            #[cfg(feature = "signatures")]
+            signature: header.signature.into(),
+            #[cfg(feature = "signatures")]
+            signable_bytes: header.retained_signable_bytes.into(),
+        })
+    }
+
+    /// Returns the size of the `count` data points ending the `payload`.
+    ///
+    /// The data points of the package flagged with the `VARIABLE_VALUE_SIZE` are walked
+    /// through their own value sizes, the other ones are of the uniform `value_size`.
+    fn data_points_size(payload: &[u8], count: usize, value_size: usize) -> Result<usize, Error> {
+        if value_size != VARIABLE_VALUE_SIZE {
+            return Ok(count * (value_size + DATA_FEED_ID_BS));
+        }
+
+        let mut size = 0;
+        for _ in 0..count {
+            let end = payload.len().saturating_sub(size);
+            let mut value_size_bytes =
+                payload[end.saturating_sub(DATA_POINT_VALUE_BYTE_SIZE_BS)..end].to_vec();
+            let value_size = value_size_bytes.try_trim_end(DATA_POINT_VALUE_BYTE_SIZE_BS)?;
+            Self::check_value_size(value_size)?;
+
+            size += DATA_POINT_VALUE_BYTE_SIZE_BS + value_size + DATA_FEED_ID_BS;
+        }
+
+        Ok(size)
+    }
+
     fn trim_data_points(
         payload: &mut Vec<u8>,
         count: usize,
         value_size: usize,
     ) -> Result<Vec<DataPoint>, Error> {
         Self::check_data_point_count(count)?;
+        if value_size != VARIABLE_VALUE_SIZE {
+            Self::check_value_size(value_size)?;
+        }
 
         let mut data_points = Vec::with_capacity(count);
 
         for _ in 0..count {
+            let value_size = if value_size == VARIABLE_VALUE_SIZE {
+                let value_size = payload.try_trim_end(DATA_POINT_VALUE_BYTE_SIZE_BS)?;
+                Self::check_value_size(value_size)?;
+
+                value_size
+            } else {
+                value_size
+            };
             let data_point = Self::trim_data_point(payload, value_size);
             data_points.push(data_point);
         }
@@ -101,9 +485,12 @@ impl<Env: Environment, C: Crypto> PayloadDecoder<Env, C> {
         Ok(data_points)
     }
 
+    /// The feed id is taken as the exact 32 bytes of the payload, so the full-width ones, like the hashes, are kept as they are.
     fn trim_data_point(payload: &mut Vec<u8>, value_size: usize) -> DataPoint {
         let value: Vec<_> = payload.trim_end(value_size);
-        let feed_id = payload.trim_end(DATA_FEED_ID_BS);
+        let feed_id: Vec<_> = payload.trim_end(DATA_FEED_ID_BS);
+        let feed_id = <[u8; DATA_FEED_ID_BS]>::try_from(feed_id.as_slice())
+            .map_or_else(|_| feed_id.into(), FeedId::from);
 
         DataPoint {
             value: value.into(),
@@ -111,12 +498,21 @@ impl<Env: Environment, C: Crypto> PayloadDecoder<Env, C> {
         }
     }
 
+    /// The values wider than the `Value` can't be converted, so they are rejected before being trimmed.
+    #[inline(always)]
+    fn check_value_size(value_size: usize) -> Result<(), Error> {
+        ensure(
+            value_size <= VALUE_SIZE,
+            Error::SizeNotSupported(value_size),
+        )
+    }
+
     #[inline(always)]
     fn check_data_point_count(count: usize) -> Result<(), Error> {
-        if count > DATA_POINT_COUNT_MAX_VALUE || count == 0 {
-            return Err(Error::SizeNotSupported(count));
-        }
-        Ok(())
+        ensure(
+            (1..=DATA_POINT_COUNT_MAX_VALUE).contains(&count),
+            Error::SizeNotSupported(count),
+        )
     }
 }
 
@@ -128,20 +524,23 @@ mod tests {
     use core::ops::Shr;
 
     use crate::{
+        core::test_helpers::{from_ticker, SAMPLE_PAYLOAD_TIMESTAMP},
+        crypto::CryptoError,
         default_ext::DefaultCrypto,
         helpers::hex::{hex_to_bytes, sample_payload_bytes, sample_payload_hex},
         network::{error::Error, StdEnv},
         protocol::{
             constants::{
                 DATA_FEED_ID_BS, DATA_POINTS_COUNT_BS, DATA_POINT_VALUE_BYTE_SIZE_BS,
-                REDSTONE_MARKER_BS, SIGNATURE_BS, TIMESTAMP_BS,
+                REDSTONE_MARKER, REDSTONE_MARKER_BS, SIGNATURE_BS, TIMESTAMP_BS,
+                VARIABLE_VALUE_SIZE,
             },
             data_package::DataPackage,
             data_point::DataPoint,
             PayloadDecoder,
         },
         types::VALUE_SIZE,
-        Value,
+        TimestampMillis, Value,
     };
 
     type TestProcessor = PayloadDecoder<StdEnv, DefaultCrypto>;
@@ -170,6 +569,27 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_trim_metadata_with_inflated_unsigned_metadata_size() {
+        // the unsigned metadata size of 0xffffff bytes, while only 5 bytes precede it
+        let mut bytes = hex_to_bytes("9e0294371c".to_owned() + "ffffff");
+        let result = TestProcessor::trim_metadata(&mut bytes);
+
+        assert_eq!(result, Err(Error::BufferOverflow(0xffffff, 5)));
+
+        let mut bytes = sample_payload_bytes();
+        let len = bytes.len();
+        bytes[len - REDSTONE_MARKER_BS - 3..len - REDSTONE_MARKER_BS].copy_from_slice(&[0xff; 3]);
+
+        assert_eq!(
+            TestProcessor::make_payload(&mut bytes).map(|_| ()),
+            Err(Error::BufferOverflow(
+                0xffffff,
+                len - REDSTONE_MARKER_BS - 3
+            ))
+        );
+    }
+
     #[test]
     fn test_trim_payload() {
         let payload_hex = sample_payload_bytes();
@@ -189,6 +609,155 @@ mod tests {
         assert_eq!(payload.data_packages.len(), 15);
     }
 
+    #[test]
+    fn test_make_payload_for_feeds() {
+        let feed_ids = ["ETH", "AVAX"].map(from_ticker);
+        let payload = TestProcessor::make_payload(&mut sample_payload_bytes()).unwrap();
+        let selective_payload =
+            TestProcessor::make_payload_for_feeds(&mut sample_payload_bytes(), &feed_ids[..1])
+                .unwrap();
+
+        let expected: Vec<_> = payload
+            .data_packages
+            .into_iter()
+            .filter(|data_package| data_package.data_points[0].feed_id == feed_ids[0])
+            .collect();
+
+        assert_eq!(expected.len(), 5);
+        assert_eq!(selective_payload.data_packages, expected);
+
+        let selective_payload =
+            TestProcessor::make_payload_for_feeds(&mut sample_payload_bytes(), &feed_ids).unwrap();
+
+        assert_eq!(selective_payload.data_packages.len(), 10);
+    }
+
+    #[test]
+    fn test_make_payload_for_no_feeds() {
+        let payload = TestProcessor::make_payload_for_feeds(&mut sample_payload_bytes(), &[]);
+
+        assert_eq!(payload.unwrap().data_packages, Vec::new());
+    }
+
+    #[test]
+    fn test_make_payload_in_timestamp_range() {
+        let timestamp = TimestampMillis::from_millis(SAMPLE_PAYLOAD_TIMESTAMP);
+        let payload = TestProcessor::make_payload(&mut sample_payload_bytes()).unwrap();
+        let range_payload = TestProcessor::make_payload_in_timestamp_range(
+            &mut sample_payload_bytes(),
+            (timestamp, timestamp),
+        );
+
+        assert_eq!(range_payload.unwrap(), payload);
+    }
+
+    #[test]
+    fn test_make_payload_out_of_timestamp_range() {
+        let timestamp = TimestampMillis::from_millis(SAMPLE_PAYLOAD_TIMESTAMP);
+
+        let result = TestProcessor::make_payload_in_timestamp_range(
+            &mut sample_payload_bytes(),
+            (0.into(), (SAMPLE_PAYLOAD_TIMESTAMP - 1).into()),
+        );
+        assert_eq!(result, Err(Error::TimestampTooFuture(0, timestamp)));
+
+        let result = TestProcessor::make_payload_in_timestamp_range(
+            &mut sample_payload_bytes(),
+            (timestamp.add(1), u64::MAX.into()),
+        );
+        assert_eq!(result, Err(Error::TimestampTooOld(0, timestamp)));
+    }
+
+    #[test]
+    fn test_make_payload_with_scratch() {
+        let payload = TestProcessor::make_payload(&mut sample_payload_bytes()).unwrap();
+        let mut scratch = [0u8; TestProcessor::scratch_size(1, VALUE_SIZE)];
+
+        let scratch_payload =
+            TestProcessor::make_payload_with_scratch(&mut sample_payload_bytes(), &mut scratch);
+
+        assert_eq!(scratch.len(), 77);
+        assert_eq!(
+            scratch_payload.unwrap().data_packages,
+            payload.data_packages
+        );
+    }
+
+    #[test]
+    fn test_make_payload_with_undersized_scratch() {
+        let mut scratch = [0u8; 76];
+
+        let res =
+            TestProcessor::make_payload_with_scratch(&mut sample_payload_bytes(), &mut scratch);
+
+        assert_eq!(res.map(|_| ()), Err(Error::BufferOverflow(77, 76)));
+    }
+
+    #[test]
+    fn test_make_payload_unmarked() {
+        let marked = TestProcessor::make_payload(&mut sample_payload_bytes()).unwrap();
+
+        let mut bytes = sample_payload_bytes();
+        bytes.truncate(bytes.len() - REDSTONE_MARKER_BS);
+        let unmarked = TestProcessor::make_payload_unmarked(&mut bytes).unwrap();
+
+        assert_eq!(unmarked.data_packages, marked.data_packages);
+    }
+
+    #[test]
+    fn test_make_payload_without_marker() {
+        let mut bytes = sample_payload_bytes();
+        bytes.truncate(bytes.len() - REDSTONE_MARKER_BS);
+
+        let res = TestProcessor::make_payload(&mut bytes);
+
+        assert!(matches!(res, Err(Error::WrongRedStoneMarker(_))));
+    }
+
+    #[test]
+    fn test_make_payloads() {
+        let mut bytes = sample_payload_bytes();
+        bytes.extend(sample_payload_bytes());
+
+        let payloads = TestProcessor::make_payloads(&mut bytes).unwrap();
+
+        assert_eq!(payloads.len(), 2);
+        assert!(payloads
+            .iter()
+            .all(|payload| payload.data_packages.len() == 15));
+        assert_eq!(payloads[0].data_packages, payloads[1].data_packages);
+    }
+
+    #[test]
+    fn test_make_payloads_single() {
+        let payloads = TestProcessor::make_payloads(&mut sample_payload_bytes()).unwrap();
+
+        assert_eq!(payloads.len(), 1);
+        assert_eq!(payloads[0].data_packages.len(), 15);
+    }
+
+    #[test]
+    fn test_make_payloads_with_leftover() {
+        let mut bytes = hex_to_bytes("12".to_owned() + &sample_payload_hex());
+        bytes.extend(sample_payload_bytes());
+
+        let res = TestProcessor::make_payloads(&mut bytes);
+
+        assert!(matches!(res, Err(Error::WrongRedStoneMarker(marker)) if marker == [0x12]));
+    }
+
+    #[test]
+    fn test_make_payload_empty() {
+        assert_eq!(
+            TestProcessor::make_payload(&mut vec![]),
+            Err(Error::EmptyPayload)
+        );
+        assert_eq!(
+            TestProcessor::make_payload(&mut REDSTONE_MARKER.to_vec()),
+            Err(Error::EmptyPayload)
+        );
+    }
+
     #[test]
     fn test_make_payload_with_prefix() {
         let payload_hex = sample_payload_hex();
@@ -268,10 +837,34 @@ mod tests {
         }
     }
 
-    #[should_panic(expected = "range end index 64 out of range for slice of length 0")]
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_trim_data_packages_parallel() {
+        use crate::protocol::marker::trim_redstone_marker;
+
+        let mut bytes = sample_payload_bytes();
+        trim_redstone_marker(&mut bytes).unwrap();
+        let count = TestProcessor::trim_metadata(&mut bytes).unwrap();
+        let mut parallel_bytes = bytes.clone();
+
+        let data_packages = TestProcessor::trim_data_packages(&mut bytes, count).unwrap();
+        let parallel_data_packages =
+            TestProcessor::trim_data_packages_parallel(&mut parallel_bytes, count).unwrap();
+
+        assert_eq!(data_packages.len(), 15);
+        assert_eq!(parallel_data_packages, data_packages);
+        assert_eq!(parallel_bytes, bytes);
+    }
+
     #[test]
     fn test_trim_data_packages_bigger_number() {
-        test_trim_data_packages_of(3, "");
+        let mut bytes = hex_to_bytes(DATA_PACKAGE_BYTES_1.to_owned() + DATA_PACKAGE_BYTES_2);
+        let result = TestProcessor::trim_data_packages(&mut bytes, 3);
+
+        assert_eq!(
+            result,
+            Err(Error::CryptographicError(CryptoError::Signature(vec![])))
+        );
     }
 
     #[test]
@@ -334,11 +927,64 @@ mod tests {
             }],
             timestamp: 1707144580000.into(),
             signer_address: hex_to_bytes(signer_address.into()).into(),
+            #[cfg(feature = "signatures")]
+            signature: result.signature.clone(),
+            #[cfg(feature = "signatures")]
+            signable_bytes: result.signable_bytes.clone(),
         };
 
         assert_eq!(result, data_package);
     }
 
+    #[test]
+    fn test_data_package_from_bytes() {
+        let data_package =
+            DataPackage::from_bytes::<DefaultCrypto>(&hex_to_bytes(DATA_PACKAGE_BYTES_1.into()))
+                .unwrap();
+
+        verify_data_package(data_package, VALUE_1, SIGNER_ADDRESS_1);
+    }
+
+    #[test]
+    fn test_data_package_from_bytes_with_prefix() {
+        let bytes = hex_to_bytes("da4687f1914a1c".to_owned() + DATA_PACKAGE_BYTES_1);
+
+        assert_eq!(
+            DataPackage::from_bytes::<DefaultCrypto>(&bytes),
+            Err(Error::NonEmptyPayloadRemainder(7))
+        );
+    }
+
+    #[cfg(feature = "signatures")]
+    #[test]
+    fn test_trim_data_package_signature() {
+        let mut bytes = hex_to_bytes(DATA_PACKAGE_BYTES_1.into());
+        let signature = bytes[bytes.len() - SIGNATURE_BS..].to_vec();
+
+        let result = TestProcessor::trim_data_package(&mut bytes).unwrap();
+
+        assert_eq!(result.signature, signature.into());
+        assert_eq!(result.recovery_id(), Some(1));
+    }
+
+    #[cfg(feature = "signatures")]
+    #[test]
+    fn test_trim_data_package_signable_bytes() {
+        use crate::crypto::Crypto;
+
+        let mut bytes = hex_to_bytes(DATA_PACKAGE_BYTES_1.into());
+        let signable_bytes =
+            bytes[bytes.len() - DATA_PACKAGE_SIZE..bytes.len() - SIGNATURE_BS].to_vec();
+
+        let result = TestProcessor::trim_data_package(&mut bytes).unwrap();
+
+        assert_eq!(result.signable_bytes(), &signable_bytes[..]);
+        assert_eq!(
+            DefaultCrypto::recover_address(result.signable_bytes(), result.signature.as_slice()),
+            Ok(result.signer_address)
+        );
+    }
+
     #[test]
     fn test_trim_data_points() {
         let mut bytes = hex_to_bytes(DATA_POINT_BYTES_TAIL.into());
@@ -410,6 +1056,17 @@ mod tests {
         assert_eq!(res, Err(Error::SizeNotSupported(u16::MAX as usize + 1)));
     }
 
+    #[test]
+    fn test_trim_data_points_too_wide_value() {
+        let mut bytes = hex_to_bytes("ff".to_owned() + DATA_POINT_BYTES_TAIL);
+        let input = bytes.clone();
+
+        let res = TestProcessor::trim_data_points(&mut bytes, 1, VALUE_SIZE + 1);
+
+        assert_eq!(res, Err(Error::SizeNotSupported(VALUE_SIZE + 1)));
+        assert_eq!(bytes, input);
+    }
+
     #[test]
     fn test_trim_data_point() -> Result<(), Error> {
         test_trim_data_point_of(DATA_POINT_BYTES_TAIL, 32, 1, VALUE.into())
@@ -458,6 +1115,72 @@ mod tests {
         Ok(())
     }
 
+    // The "ETH" data point with the 2-byte value 0x1234, followed by the "BTC" one with the 1-byte value 0x56,
+    // each followed by its value size.
+    const VARIABLE_SIZE_DATA_POINTS: &str = concat!(
+        "4554480000000000000000000000000000000000000000000000000000000000",
+        "1234",
+        "00000002",
+        "4254430000000000000000000000000000000000000000000000000000000000",
+        "56",
+        "00000001"
+    );
+
+    #[test]
+    fn test_trim_data_points_variable_value_size() -> Result<(), Error> {
+        let mut bytes = hex_to_bytes(VARIABLE_SIZE_DATA_POINTS.into());
+
+        let size = TestProcessor::data_points_size(&bytes, 2, VARIABLE_VALUE_SIZE)?;
+        let result = TestProcessor::trim_data_points(&mut bytes, 2, VARIABLE_VALUE_SIZE)?;
+
+        assert_eq!(size, VARIABLE_SIZE_DATA_POINTS.len() / 2);
+        assert_eq!(bytes, Vec::<u8>::new());
+        assert_eq!(
+            result,
+            vec![
+                DataPoint {
+                    feed_id: from_ticker("BTC"),
+                    value: 0x56u8.into(),
+                },
+                DataPoint {
+                    feed_id: from_ticker("ETH"),
+                    value: 0x1234u16.into(),
+                },
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_data_points_size_uniform_value_size() -> Result<(), Error> {
+        let bytes = hex_to_bytes(DATA_POINT_BYTES_TAIL.into());
+
+        assert_eq!(
+            TestProcessor::data_points_size(&bytes, 1, 32)?,
+            DATA_POINT_BYTES_TAIL.len() / 2
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_trim_data_points_variable_value_size_too_wide() {
+        let mut bytes = hex_to_bytes(
+            VARIABLE_SIZE_DATA_POINTS[..VARIABLE_SIZE_DATA_POINTS.len() - 8].to_owned()
+                + "00000021",
+        );
+
+        assert_eq!(
+            TestProcessor::data_points_size(&bytes, 2, VARIABLE_VALUE_SIZE),
+            Err(Error::SizeNotSupported(VALUE_SIZE + 1))
+        );
+        assert_eq!(
+            TestProcessor::trim_data_points(&mut bytes, 2, VARIABLE_VALUE_SIZE),
+            Err(Error::SizeNotSupported(VALUE_SIZE + 1))
+        );
+    }
+
     fn test_trim_data_point_of(
         value: &str,
         size: usize,
@@ -491,3 +1214,57 @@ mod tests {
         assert_eq!(result, data_point);
     }
 }
+
+#[cfg(test)]
+#[cfg(feature = "helpers")]
+#[cfg(feature = "default-crypto")]
+#[cfg(not(target_arch = "wasm32"))]
+mod fuzz_tests {
+    use alloc::vec::Vec;
+
+    use proptest::{collection::vec, prelude::*, sample::Index};
+
+    use crate::{
+        default_ext::DefaultCrypto, helpers::hex::sample_payload_bytes, network::StdEnv,
+        protocol::PayloadDecoder,
+    };
+
+    type TestProcessor = PayloadDecoder<StdEnv, DefaultCrypto>;
+
+    fn assert_decodes_or_fails(mut bytes: Vec<u8>) {
+        if let Ok(payload) = TestProcessor::make_payload(&mut bytes) {
+            assert!(bytes.is_empty());
+            assert!(payload
+                .data_packages
+                .iter()
+                .all(|package| !package.data_points.is_empty()));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_make_payload_random_bytes(bytes in vec(any::<u8>(), 0..1024)) {
+            assert_decodes_or_fails(bytes);
+        }
+
+        #[test]
+        fn test_make_payload_mutated_sample(mutations in vec((any::<Index>(), any::<u8>()), 1..8)) {
+            let mut bytes = sample_payload_bytes();
+            for (index, byte) in mutations {
+                let index = index.index(bytes.len());
+                bytes[index] = byte;
+            }
+
+            assert_decodes_or_fails(bytes);
+        }
+
+        #[test]
+        fn test_make_payload_truncated_sample(start in any::<Index>(), end in any::<Index>()) {
+            let bytes = sample_payload_bytes();
+            let (start, end) = (start.index(bytes.len()), end.index(bytes.len()));
+            let (start, end) = (start.min(end), start.max(end));
+
+            assert_decodes_or_fails(bytes[start..end].to_vec());
+        }
+    }
+}
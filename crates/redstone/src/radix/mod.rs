@@ -50,3 +50,29 @@ impl Crypto for RadixCrypto {
         Ok(pk.into())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use core::fmt::Debug;
+
+    use scrypto::prelude::{scrypto_decode, scrypto_encode, ScryptoDecode, ScryptoEncode};
+
+    use crate::{FeedId, SignerAddress, TimestampMillis, Value};
+
+    fn assert_round_trip<T: ScryptoEncode + ScryptoDecode + PartialEq + Debug>(value: T) {
+        let bytes = scrypto_encode(&value).unwrap();
+
+        assert_eq!(scrypto_decode::<T>(&bytes).unwrap(), value);
+    }
+
+    #[test]
+    fn test_scrypto_sbor_round_trip() {
+        let feed_id: FeedId = "ETH".parse().unwrap();
+        let signer: SignerAddress = "1ea62d73edF8ac05dfcea1a34b9796e937a29eFF".parse().unwrap();
+
+        assert_round_trip(Value::from(236389750361u128));
+        assert_round_trip(feed_id);
+        assert_round_trip(signer);
+        assert_round_trip(TimestampMillis::from_millis(1707307760000));
+    }
+}
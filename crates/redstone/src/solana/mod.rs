@@ -73,10 +73,45 @@ impl Crypto for SolanaCrypto {
 #[cfg(test)]
 #[cfg(feature = "helpers")]
 mod tests {
-    use crate::{crypto::recovery_key_tests::run_all_testcases, solana::SolanaCrypto};
+    use alloc::string::ToString;
+
+    use anchor_lang::error::Error as AnchorLangError;
+
+    use crate::{
+        core::test_helpers::from_ticker,
+        crypto::{recovery_key_tests::run_all_testcases, CryptoError},
+        network::error::Error,
+        solana::SolanaCrypto,
+    };
 
     #[test]
     fn test_default_crypto_impl() {
         run_all_testcases::<SolanaCrypto>();
     }
+
+    #[test]
+    fn test_anchor_error_codes() {
+        let feed_id = from_ticker("ETH");
+
+        // The codes are decoded off-chain, so they must stay the same across the releases.
+        for (error, expected_code) in [
+            (Error::ConfigInsufficientSignerCount(1, 2), 514),
+            (Error::EmptyPayload, 528),
+            (Error::CryptographicError(CryptoError::SignatureHighS), 794),
+            (Error::TimestampTooOld(3, 1.into()), 1003),
+            (Error::TimestampTooFuture(3, 1.into()), 1053),
+            (Error::InsufficientSignerCount(2, 3, feed_id), 2023),
+        ] {
+            let expected_msg = error.to_string();
+
+            match AnchorLangError::from(error) {
+                AnchorLangError::AnchorError(anchor_error) => {
+                    assert_eq!(anchor_error.error_name, "rust-sdk");
+                    assert_eq!(anchor_error.error_code_number, expected_code);
+                    assert_eq!(anchor_error.error_msg, expected_msg);
+                }
+                other => panic!("Unexpected error: {other:?}"),
+            }
+        }
+    }
 }
@@ -0,0 +1,65 @@
+//! Starknet extension
+//!
+//! Conversions of the values to the representation of the `u256` of Cairo,
+//! the pair of the `low` and `high` 128-bit halves, each fitting a felt.
+
+use crate::Value;
+
+impl Value {
+    /// Splits the value into the `(low, high)` 128-bit halves, like the `u256 { low, high }` of Cairo.
+    pub fn to_felt_pair(&self) -> (u128, u128) {
+        let (high, low) = self.0.split_at(16);
+
+        (
+            u128::from_be_bytes(low.try_into().unwrap()),
+            u128::from_be_bytes(high.try_into().unwrap()),
+        )
+    }
+
+    /// Joins the `low` and `high` 128-bit halves, like the `u256 { low, high }` of Cairo, into the value.
+    pub fn from_felt_pair(low: u128, high: u128) -> Value {
+        let mut bytes = [0; 32];
+        bytes[..16].copy_from_slice(&high.to_be_bytes());
+        bytes[16..].copy_from_slice(&low.to_be_bytes());
+
+        Value(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    use crate::Value;
+
+    #[test]
+    fn test_felt_pair_below_128_bits() {
+        let value = Value::from(236389750361u128);
+
+        assert_eq!(value.to_felt_pair(), (236389750361, 0));
+        assert_eq!(Value::from_felt_pair(236389750361, 0), value);
+        assert_eq!(Value::from(u128::MAX).to_felt_pair(), (u128::MAX, 0));
+    }
+
+    #[test]
+    fn test_felt_pair_crossing_128_bits() {
+        let value = Value::from_u256(primitive_types::U256::from(u128::MAX) + 1);
+
+        assert_eq!(value.to_felt_pair(), (0, 1));
+        assert_eq!(Value::from_felt_pair(0, 1), value);
+
+        let value = Value::from_felt_pair(5, 7);
+        assert_eq!(
+            value.to_u256(),
+            (primitive_types::U256::from(7u8) << 128) + 5
+        );
+        assert_eq!(value.to_felt_pair(), (5, 7));
+    }
+
+    #[test]
+    fn test_felt_pair_max() {
+        assert_eq!(Value::max().to_felt_pair(), (u128::MAX, u128::MAX));
+        assert_eq!(Value::from_felt_pair(u128::MAX, u128::MAX), Value::max());
+    }
+}
@@ -0,0 +1,77 @@
+//! Sui extension
+//!
+//! Implementation of the config suited for the Sui network, with the crypto operations matching
+//! the `sui::ecdsa_k256::secp256k1_ecrecover` and `sui::hash::keccak256` natives,
+//! usable for the off-chain verifiers and the Move native bindings.
+//!
+//! The Sui natives accept the raw recovery ids (`0..=3`), not the `27`/`28` ethereum `v` values.
+//! The `v` byte of the signature is normalized in the [`Crypto::recover_address`] function,
+//! and only the `0` and `1` recovery ids are accepted, see the [`check_recovery_byte`].
+//!
+//! The `secp256k1_ecrecover` native returns the 33-byte compressed public key, which must be
+//! decompressed with the `sui::ecdsa_k256::decompress_pubkey` before deriving the signer address,
+//! so the [`SuiCrypto`] returns the 65-byte uncompressed key, as the other implementations do.
+
+use k256::ecdsa::{RecoveryId, Signature, VerifyingKey};
+
+use crate::{
+    crypto::{check_recovery_byte, Crypto, CryptoError},
+    default_ext::keccak256,
+    network::StdEnv,
+    Bytes, RedStoneConfigImpl,
+};
+
+/// Implementation of `RedstoneConfig` specialized for operations on the Sui.
+pub type SuiRedStoneConfig = RedStoneConfigImpl<SuiCrypto, SuiEnv>;
+
+pub type SuiEnv = StdEnv;
+pub enum SuiCrypto {}
+
+impl Crypto for SuiCrypto {
+    type KeccakOutput = [u8; 32];
+
+    fn keccak256(input: impl AsRef<[u8]>) -> Self::KeccakOutput {
+        keccak256(input)
+    }
+
+    fn recover_public_key(
+        recovery_byte: u8,
+        signature_bytes: impl AsRef<[u8]>,
+        message_hash: Self::KeccakOutput,
+    ) -> Result<Bytes, CryptoError> {
+        let recovery_id = RecoveryId::from_byte(check_recovery_byte(recovery_byte)?)
+            .ok_or(CryptoError::RecoveryByte(recovery_byte))?;
+        let signature = Signature::try_from(signature_bytes.as_ref())
+            .map_err(|_| CryptoError::Signature(signature_bytes.as_ref().to_vec()))?;
+
+        let key = VerifyingKey::recover_from_prehash(&message_hash, &signature, recovery_id)
+            .map_err(|_| CryptoError::RecoverPreHash)?;
+
+        Ok(key.to_encoded_point(false).as_bytes().to_vec().into())
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "helpers")]
+mod tests {
+    use crate::{
+        crypto::{recovery_key_tests::run_all_testcases, CryptoError},
+        sui::SuiCrypto,
+        Crypto,
+    };
+
+    #[test]
+    fn test_sui_crypto_impl() {
+        run_all_testcases::<SuiCrypto>();
+    }
+
+    #[test]
+    fn test_sui_crypto_rejects_high_recovery_ids() {
+        for recovery_byte in [2, 3] {
+            assert_eq!(
+                SuiCrypto::recover_public_key(recovery_byte, [1; 64], [0; 32]),
+                Err(CryptoError::RecoveryByte(recovery_byte))
+            );
+        }
+    }
+}
@@ -0,0 +1,50 @@
+//! TON extension
+//!
+//! Implementation of the config suited for the off-chain verifiers of the payloads destined to the TON contracts,
+//! with the crypto operations of the [`DefaultCrypto`], i.e. the `k256` and `sha3` libraries.
+//!
+//! The TON contracts recover the signers with the `ECRECOVER` instruction of the TVM, taking the raw recovery id
+//! (`0` or `1`) instead of the `27`/`28` ethereum `v` values; the `v` byte of the signature is normalized
+//! in the [`Crypto::recover_address`] function the same way. The recovered key is the 65-byte uncompressed one,
+//! so the signers are identified by the same keccak256-derived 20-byte addresses as on the other networks,
+//! not by the TON wallet addresses, which are unrelated to the signing keys of the RedStone nodes.
+
+use crate::{
+    crypto::{Crypto, CryptoError},
+    default_ext::DefaultCrypto,
+    network::StdEnv,
+    Bytes, RedStoneConfigImpl,
+};
+
+/// Implementation of `RedstoneConfig` specialized for the verification of the TON-destined payloads.
+pub type TonRedStoneConfig = RedStoneConfigImpl<TonCrypto, TonEnv>;
+
+pub type TonEnv = StdEnv;
+pub enum TonCrypto {}
+
+impl Crypto for TonCrypto {
+    type KeccakOutput = [u8; 32];
+
+    fn keccak256(input: impl AsRef<[u8]>) -> Self::KeccakOutput {
+        DefaultCrypto::keccak256(input)
+    }
+
+    fn recover_public_key(
+        recovery_byte: u8,
+        signature_bytes: impl AsRef<[u8]>,
+        message_hash: Self::KeccakOutput,
+    ) -> Result<Bytes, CryptoError> {
+        DefaultCrypto::recover_public_key(recovery_byte, signature_bytes, message_hash)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "helpers")]
+mod tests {
+    use crate::{crypto::recovery_key_tests::run_all_testcases, ton::TonCrypto};
+
+    #[test]
+    fn test_ton_crypto_impl() {
+        run_all_testcases::<TonCrypto>();
+    }
+}
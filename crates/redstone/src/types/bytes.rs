@@ -1,7 +1,33 @@
-use alloc::vec::Vec;
+use alloc::{string::String, vec::Vec};
+
+use crate::network::as_str::AsHexStr;
+
 /// Type wrapping bytes represantion.
 #[derive(Clone, PartialEq, Eq, Debug, Default)]
-pub struct Bytes(pub Vec<u8>);
+pub struct Bytes(Vec<u8>);
+
+impl Bytes {
+    pub fn as_slice(&self) -> &[u8] {
+        &self.0
+    }
+
+    pub fn into_vec(self) -> Vec<u8> {
+        self.0
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the lowercase hex representation of the bytes, without the `0x` prefix.
+    pub fn to_hex(&self) -> String {
+        self.as_slice().as_hex_str()
+    }
+}
 
 impl From<Vec<u8>> for Bytes {
     fn from(value: Vec<u8>) -> Self {
@@ -14,3 +40,32 @@ impl AsRef<[u8]> for Bytes {
         self.0.as_ref()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    use crate::Bytes;
+
+    #[test]
+    fn test_accessors() {
+        let bytes = Bytes::from(vec![0x00, 0xab, 0x1f]);
+
+        assert_eq!(bytes.as_slice(), &[0x00, 0xab, 0x1f]);
+        assert_eq!(bytes.as_ref(), bytes.as_slice());
+        assert_eq!(bytes.len(), 3);
+        assert!(!bytes.is_empty());
+        assert_eq!(bytes.to_hex(), "00ab1f");
+        assert_eq!(bytes.into_vec(), vec![0x00, 0xab, 0x1f]);
+    }
+
+    #[test]
+    fn test_empty() {
+        let bytes = Bytes::default();
+
+        assert_eq!(bytes.len(), 0);
+        assert!(bytes.is_empty());
+        assert_eq!(bytes.to_hex(), "");
+    }
+}
@@ -1,17 +1,92 @@
+#[cfg(feature = "serde")]
+use alloc::string::ToString;
 use alloc::vec::Vec;
+use core::{
+    fmt::{Debug, Display, Formatter},
+    str::FromStr,
+};
 
 #[cfg(feature = "radix")]
 use scrypto::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Serialize, Serializer};
 
-use crate::types::{Sanitized, VALUE_SIZE};
+use crate::{
+    network::{as_str::AsHexStr, error::Error},
+    types::{Sanitized, VALUE_SIZE},
+};
 
 /// Type describing feed ids.
 /// We expect FeedId to be byte string like b"EUR"
 /// converted to bytearray and padded with zeroes to the right.
-#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash, PartialOrd, Ord)]
+///
+/// Both `Debug` and `Display` show the ASCII ticker when the feed id is printable, falling back to hex otherwise.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[cfg_attr(feature = "radix", derive(ScryptoSbor))]
 pub struct FeedId([u8; VALUE_SIZE]);
 
+impl FeedId {
+    /// Makes the feed id of the ASCII ticker, like `"ETH"`, the same way the contracts encode it.
+    ///
+    /// The ticker bytes are left-justified and padded with zeroes to the right, up to the 32 bytes,
+    /// so `"ETH"` makes `0x455448` followed by 29 zero bytes.
+    /// Fails with `Error::InvalidFeedIdLength` for the empty ticker or the one longer than 32 bytes.
+    pub fn from_ticker(ticker: &str) -> Result<Self, Error> {
+        if ticker.is_empty() || ticker.len() > VALUE_SIZE {
+            return Err(Error::InvalidFeedIdLength(ticker.len()));
+        }
+
+        let mut buff = [0; VALUE_SIZE];
+        buff[0..ticker.len()].copy_from_slice(ticker.as_bytes());
+
+        Ok(Self(buff))
+    }
+
+    /// Returns the ASCII ticker of the feed id, like `"ETH"`.
+    ///
+    /// Returns `None` when the feed id, with trailing zeroes trimmed, is empty
+    /// or contains bytes other than printable ASCII characters.
+    pub fn as_ticker(&self) -> Option<&str> {
+        let len = self.0.iter().rposition(|&byte| byte != 0)? + 1;
+        let ticker = &self.0[..len];
+
+        if !ticker
+            .iter()
+            .all(|&byte| byte.is_ascii_graphic() || byte == b' ')
+        {
+            return None;
+        }
+
+        core::str::from_utf8(ticker).ok()
+    }
+}
+
+impl Debug for FeedId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self.as_ticker() {
+            Some(ticker) => write!(f, "FeedId({:?})", ticker),
+            None => write!(f, "FeedId(0x{})", (&self.0[..]).as_hex_str()),
+        }
+    }
+}
+
+impl Display for FeedId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self.as_ticker() {
+            Some(ticker) => write!(f, "{}", ticker),
+            None => write!(f, "0x{}", (&self.0[..]).as_hex_str()),
+        }
+    }
+}
+
+/// Serializes the feed id as its [`Display`] representation: the ticker if possible, the hex string otherwise.
+#[cfg(feature = "serde")]
+impl Serialize for FeedId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
 impl From<FeedId> for [u8; VALUE_SIZE] {
     fn from(value: FeedId) -> Self {
         value.0
@@ -59,3 +134,111 @@ impl From<Vec<u8>> for FeedId {
         Self(buff)
     }
 }
+
+/// Parses the feed id from its ASCII ticker, like `"ETH"`, see [`FeedId::from_ticker`].
+impl FromStr for FeedId {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_ticker(s)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "helpers")]
+mod tests {
+    use alloc::{format, string::ToString};
+    use core::str::FromStr;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    use crate::{
+        core::test_helpers::from_ticker, network::error::Error, types::VALUE_SIZE, FeedId,
+    };
+
+    const HASH_FEED_ID: [u8; VALUE_SIZE] = [0xab; VALUE_SIZE];
+
+    #[test]
+    fn test_as_ticker() {
+        assert_eq!(from_ticker("ETH").as_ticker(), Some("ETH"));
+        assert_eq!(FeedId::from(HASH_FEED_ID).as_ticker(), None);
+        assert_eq!(FeedId::from([0u8; VALUE_SIZE]).as_ticker(), None);
+    }
+
+    #[test]
+    fn test_debug_ascii() {
+        assert_eq!(format!("{:?}", from_ticker("ETH")), "FeedId(\"ETH\")");
+        assert_eq!(
+            format!("{:?}", from_ticker("SWETH/ETH")),
+            "FeedId(\"SWETH/ETH\")"
+        );
+    }
+
+    #[test]
+    fn test_debug_non_ascii() {
+        assert_eq!(
+            format!("{:?}", FeedId::from(HASH_FEED_ID)),
+            format!("FeedId(0x{})", "ab".repeat(32))
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(from_ticker("BTC").to_string(), "BTC");
+        assert_eq!(
+            FeedId::from(HASH_FEED_ID).to_string(),
+            format!("0x{}", "ab".repeat(32))
+        );
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(FeedId::from_str("ETH"), Ok(from_ticker("ETH")));
+        assert_eq!(
+            FeedId::from_str("SWETH/ETH_FUNDAMENTAL"),
+            Ok(from_ticker("SWETH/ETH_FUNDAMENTAL"))
+        );
+    }
+
+    #[test]
+    fn test_from_ticker() {
+        let mut eth = [0; VALUE_SIZE];
+        eth[..3].copy_from_slice(b"ETH");
+        let max_ticker = "A".repeat(VALUE_SIZE);
+
+        assert_eq!(FeedId::from_ticker("ETH"), Ok(FeedId::from(eth)));
+        assert_eq!(
+            FeedId::from_ticker(&max_ticker).map(<[u8; VALUE_SIZE]>::from),
+            Ok([b'A'; VALUE_SIZE])
+        );
+        assert_eq!(
+            FeedId::from_ticker(&(max_ticker + "A")),
+            Err(Error::InvalidFeedIdLength(VALUE_SIZE + 1))
+        );
+    }
+
+    #[test]
+    fn test_from_str_empty() {
+        assert_eq!(FeedId::from_str(""), Err(Error::InvalidFeedIdLength(0)));
+    }
+
+    #[test]
+    fn test_from_str_too_long() {
+        let ticker = "A".repeat(33);
+
+        assert_eq!(
+            FeedId::from_str(&ticker),
+            Err(Error::InvalidFeedIdLength(33))
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serialize() {
+        assert_eq!(
+            serde_json::to_string(&[from_ticker("ETH"), FeedId::from(HASH_FEED_ID)]).unwrap(),
+            format!(r#"["ETH","0x{}"]"#, "ab".repeat(32))
+        );
+    }
+}
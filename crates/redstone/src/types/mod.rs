@@ -5,6 +5,8 @@ mod timestamp_millis;
 mod value;
 use alloc::vec::Vec;
 
+use crate::protocol::constants::{DATA_FEED_ID_BS, SIGNER_ADDRESS_BS};
+
 pub use bytes::Bytes;
 pub use feed_id::FeedId;
 pub use signer_address::SignerAddress;
@@ -13,8 +15,16 @@ pub use value::Value;
 
 /// We dont expect value to be larger than u256.
 ///  Adjust this once this no longer hold :)
+///
+/// The byte arrays of the `Value`, `FeedId` and `SignerAddress` are all sized by it,
+/// so the compile-time checks below point at the conversions to revisit when it changes.
 pub const VALUE_SIZE: usize = 32;
 
+// The `Value` is converted from and to the `U256`, using all of its bytes.
+const _: () = assert!(VALUE_SIZE == core::mem::size_of::<primitive_types::U256>());
+// The feed ids and the signer addresses of the payload must fit their byte arrays.
+const _: () = assert!(VALUE_SIZE >= DATA_FEED_ID_BS && VALUE_SIZE >= SIGNER_ADDRESS_BS);
+
 pub trait Sanitized {
     fn sanitized(self) -> Self;
 }
@@ -1,10 +1,36 @@
+use alloc::string::String;
+use core::str::FromStr;
+
 #[cfg(feature = "radix")]
 use scrypto::prelude::*;
+#[cfg(feature = "constant-time")]
+use subtle::ConstantTimeEq;
 
-use crate::types::{Sanitized, VALUE_SIZE};
+use crate::{
+    network::{as_str::AsHexStr, error::Error},
+    protocol::constants::SIGNER_ADDRESS_BS,
+    types::{Sanitized, VALUE_SIZE},
+    utils::hex::try_hex_to_bytes,
+    Crypto,
+};
 /// Type describing address of signer. Typically pubkey of length 20 bytes;
 /// As of right now we dont expect larger keys than 32 bytes.
-/// The address is normalized to contain only lowercase letters (A-F) -> (a-f).
+/// The address bytes are stored as they are, padded with zeroes to the right.
+///
+/// Equality is the byte equality of the canonical (decoded) address, so the letter case
+/// of the hex representation doesn't matter: `"2c59...56BF"`, `"2c59...56bf"` and `"2C59...56BF"`
+/// decode to the same bytes and produce equal `SignerAddress`es.
+/// Use [`AsHexStr::as_hex_str`] for the canonical lowercase representation
+/// and [`SignerAddress::to_checksum_string`] for the EIP-55 one.
+///
+/// # Migration
+///
+/// Previously [`SignerAddress::new`] applied `to_ascii_lowercase` to the raw address bytes,
+/// so every byte in the `0x41..=0x5A` range was replaced with the one `0x20` above it,
+/// e.g. the `0x4D` byte of `2c59617248994D...` became `0x6D`.
+/// `SignerAddress` values persisted by the previous versions (e.g. in the contract storage)
+/// may be such altered addresses and don't compare equal to the ones decoded now,
+/// so they need to be re-created from the original address strings or bytes.
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Ord, PartialOrd)]
 #[cfg_attr(feature = "radix", derive(ScryptoSbor))]
 pub struct SignerAddress([u8; VALUE_SIZE]);
@@ -17,12 +43,58 @@ impl AsRef<[u8]> for SignerAddress {
 
 impl SignerAddress {
     pub fn new(raw_address: [u8; VALUE_SIZE]) -> Self {
-        Self(
-            raw_address
-                .to_ascii_lowercase()
-                .try_into()
-                .expect("We know the length eq 32"),
-        )
+        Self(raw_address)
+    }
+
+    /// Returns the EIP-55 mixed-case checksum representation of the address (without the `0x` prefix).
+    ///
+    /// The checksum is defined for Ethereum-style addresses,
+    /// so only the first `SIGNER_ADDRESS_BS` bytes of the address are taken into account.
+    pub fn to_checksum_string<C: Crypto>(&self) -> String {
+        let lowercase = (&self.0[..SIGNER_ADDRESS_BS]).as_hex_str();
+        let hash = C::keccak256(lowercase.as_bytes());
+
+        lowercase
+            .chars()
+            .enumerate()
+            .map(|(index, character)| {
+                let nibble = (hash.as_ref()[index / 2] >> (4 * (1 - index % 2))) & 0x0f;
+
+                if nibble >= 8 {
+                    character.to_ascii_uppercase()
+                } else {
+                    character
+                }
+            })
+            .collect()
+    }
+
+    /// Compares the addresses in constant time, unlike the `==`, which may return at the first differing byte.
+    ///
+    /// The signer addresses aren't secret, so the `==` is fine for the payload processing.
+    /// Prefer this one when the outcome of the comparison, e.g. against an allowlist,
+    /// shouldn't be observable through the timing.
+    #[cfg(feature = "constant-time")]
+    pub fn ct_eq(&self, other: &Self) -> bool {
+        self.0[..].ct_eq(&other.0[..]).into()
+    }
+}
+
+/// Parses the signer address from its hex representation, with an optional `0x` prefix.
+///
+/// The hex digits are case-insensitive, so the checksummed and lowercase representations
+/// result in the same address. The address must be exactly `SIGNER_ADDRESS_BS` bytes long.
+impl FromStr for SignerAddress {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = try_hex_to_bytes(s)?;
+
+        if bytes.len() != SIGNER_ADDRESS_BS {
+            return Err(Error::InvalidSignerAddressLength(bytes.len()));
+        }
+
+        Ok(bytes.into())
     }
 }
 
@@ -37,3 +109,123 @@ impl From<Vec<u8>> for SignerAddress {
         Self::new(buff)
     }
 }
+
+#[cfg(test)]
+#[cfg(feature = "helpers")]
+mod tests {
+    use core::str::FromStr;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    #[cfg(feature = "default-crypto")]
+    use crate::default_ext::DefaultCrypto;
+    use crate::{
+        helpers::hex::make_signer_address,
+        network::{as_str::AsHexStr, error::Error},
+        SignerAddress,
+    };
+
+    const ADDRESS_1: &str = "2c59617248994D12816EE1Fa77CE0a64eEB456BF";
+    const ADDRESS_2: &str = "12470f7aBA85c8b81D63137DD5925D6EE114952b";
+
+    #[cfg(feature = "default-crypto")]
+    #[test]
+    fn test_to_checksum_string() {
+        for address in [ADDRESS_1, ADDRESS_2] {
+            let signer_address = make_signer_address(address);
+
+            assert_eq!(
+                signer_address.to_checksum_string::<DefaultCrypto>(),
+                address
+            );
+        }
+    }
+
+    #[cfg(feature = "default-crypto")]
+    #[test]
+    fn test_to_checksum_string_ignores_input_case() {
+        let lowercase = make_signer_address(&ADDRESS_1.to_lowercase());
+        let uppercase = make_signer_address(&ADDRESS_1.to_uppercase());
+
+        assert_eq!(lowercase.to_checksum_string::<DefaultCrypto>(), ADDRESS_1);
+        assert_eq!(uppercase.to_checksum_string::<DefaultCrypto>(), ADDRESS_1);
+    }
+
+    #[test]
+    fn test_new_keeps_ascii_uppercase_bytes() {
+        let uppercase_byte = make_signer_address("2c59617248994d12816ee1fa77ce0a64eeb456bf");
+        let lowercase_byte = make_signer_address("2c59617248996d12816ee1fa77ce0a64eeb456bf");
+
+        assert_eq!(uppercase_byte.as_ref()[6], 0x4D);
+        assert_eq!(lowercase_byte.as_ref()[6], 0x6D);
+        assert_ne!(uppercase_byte, lowercase_byte);
+    }
+
+    #[cfg(feature = "constant-time")]
+    #[test]
+    fn test_ct_eq() {
+        let addresses = [
+            make_signer_address(ADDRESS_1),
+            make_signer_address(&ADDRESS_1.to_lowercase()),
+            make_signer_address(ADDRESS_2),
+            make_signer_address(&ADDRESS_1[..38]),
+        ];
+
+        for a in addresses {
+            for b in addresses {
+                assert_eq!(a.ct_eq(&b), a == b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_str() {
+        let expected = make_signer_address(ADDRESS_1);
+
+        assert_eq!(SignerAddress::from_str(ADDRESS_1), Ok(expected));
+        assert_eq!(
+            SignerAddress::from_str(&format!("0x{ADDRESS_1}")),
+            Ok(expected)
+        );
+    }
+
+    #[test]
+    fn test_from_str_is_case_insensitive() {
+        let checksummed = SignerAddress::from_str(ADDRESS_1);
+        let lowercase = SignerAddress::from_str(&ADDRESS_1.to_lowercase());
+        let uppercase = SignerAddress::from_str(&ADDRESS_1.to_uppercase());
+
+        assert!(checksummed.is_ok());
+        assert_eq!(checksummed, lowercase);
+        assert_eq!(checksummed, uppercase);
+    }
+
+    #[test]
+    fn test_as_hex_str_is_lowercase() {
+        let address = make_signer_address(&ADDRESS_1.to_uppercase());
+
+        assert_eq!(address.as_hex_str(), ADDRESS_1.to_lowercase());
+    }
+
+    #[test]
+    fn test_from_str_too_short() {
+        let res = SignerAddress::from_str("0x2c59617248994D12816EE1Fa77CE0a64eEB456");
+
+        assert_eq!(res, Err(Error::InvalidSignerAddressLength(19)));
+    }
+
+    #[test]
+    fn test_from_str_too_long() {
+        let res = SignerAddress::from_str(&format!("{ADDRESS_1}00"));
+
+        assert_eq!(res, Err(Error::InvalidSignerAddressLength(21)));
+    }
+
+    #[test]
+    fn test_from_str_invalid_hex() {
+        let res = SignerAddress::from_str("0x2c59617248994D12816EE1Fa77CE0a64eEB456BG");
+
+        assert_eq!(res, Err(Error::InvalidHex("BG".into())));
+    }
+}
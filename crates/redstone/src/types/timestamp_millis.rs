@@ -1,7 +1,12 @@
 use core::fmt::Debug;
 
+#[cfg(feature = "radix")]
+use scrypto::prelude::*;
+
 /// Type describing timpestamp, we use to directly show we expect milliseconds.
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(transparent))]
+#[cfg_attr(feature = "radix", derive(ScryptoSbor))]
 pub struct TimestampMillis(u64);
 
 impl Debug for TimestampMillis {
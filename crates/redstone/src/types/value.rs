@@ -1,9 +1,46 @@
+#[cfg(feature = "serde")]
+use alloc::string::ToString;
 use alloc::vec::Vec;
 
 #[cfg(feature = "radix")]
 use scrypto::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Serialize, Serializer};
+
+use crate::{
+    network::error::Error,
+    protocol::constants::REDSTONE_PRICE_DECIMALS,
+    types::{Sanitized, VALUE_SIZE},
+    utils::median::Avg,
+};
+/// Count of the powers of ten fitting the 256 bits, 10^0..=10^77.
+const POW10_COUNT: usize = 78;
+
+/// Powers of ten as values, precomputed at the compile time, see [`Value::pow10`].
+const POW10: [Value; POW10_COUNT] = pow10_table();
+
+const fn pow10_table() -> [Value; POW10_COUNT] {
+    let mut table = [Value([0; VALUE_SIZE]); POW10_COUNT];
+    table[0].0[VALUE_SIZE - 1] = 1;
+
+    let mut n = 1;
+    while n < POW10_COUNT {
+        let mut bytes = table[n - 1].0;
+        let mut carry = 0u16;
+        let mut index = VALUE_SIZE;
+        while index > 0 {
+            index -= 1;
+            let product = bytes[index] as u16 * 10 + carry;
+            bytes[index] = product as u8;
+            carry = product >> 8;
+        }
+        table[n] = Value(bytes);
+        n += 1;
+    }
+
+    table
+}
 
-use crate::types::{Sanitized, VALUE_SIZE};
 /// Type describing values we are getting from and to network.
 /// We expect it to be at most u256 and reserve that many bytes for it.
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
@@ -15,11 +52,25 @@ macro_rules! impl_from_number {
         $number_type:ident
     ),*) => {
         $(
+            /// Converts the number to the big-endian value, aligned to the right.
             impl From<$number_type> for Value {
                 fn from(value: $number_type) -> Self {
                     Value::from_u256(primitive_types::U256::from(value))
                 }
             }
+
+            /// Converts the value back to the number,
+            /// failing with `Error::NumberOverflow` when it doesn't fit the number type.
+            impl TryFrom<Value> for $number_type {
+                type Error = Error;
+
+                fn try_from(value: Value) -> Result<Self, Self::Error> {
+                    value
+                        .to_u256()
+                        .try_into()
+                        .map_err(|_| Error::NumberOverflow(value))
+                }
+            }
          )*
     };
 }
@@ -34,7 +85,28 @@ impl Value {
         value.to_big_endian().to_vec().into()
     }
 
-    pub fn le_bytes(&self) -> [u8; 32] {
+    /// Returns the maximum value, with all of its `VALUE_SIZE` bytes set.
+    pub const fn max() -> Self {
+        Self([u8::MAX; VALUE_SIZE])
+    }
+
+    /// Converts the big-endian bytes to the value, aligned to the right.
+    ///
+    /// The bytes longer than the `VALUE_SIZE` are accepted only when the excess leading bytes are zeros,
+    /// failing with `Error::SizeNotSupported` otherwise, where the `From<Vec<u8>>` panics.
+    pub fn try_from_be_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let index = bytes.len().saturating_sub(VALUE_SIZE);
+        if bytes[..index].iter().any(|&byte| byte != 0) {
+            return Err(Error::SizeNotSupported(bytes.len()));
+        }
+
+        let mut buff = [0; VALUE_SIZE];
+        buff[VALUE_SIZE - (bytes.len() - index)..].copy_from_slice(&bytes[index..]);
+
+        Ok(Self(buff))
+    }
+
+    pub fn le_bytes(&self) -> [u8; VALUE_SIZE] {
         let mut le = self.0;
         le.reverse();
 
@@ -44,6 +116,92 @@ impl Value {
     pub fn as_be_bytes(&self) -> &[u8] {
         &self.0
     }
+
+    /// Returns the `n`-th power of ten, or `None` when it doesn't fit the 256 bits, for the `n` above 77.
+    pub const fn pow10(n: u8) -> Option<Value> {
+        if (n as usize) < POW10_COUNT {
+            Some(POW10[n as usize])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the average of the two values, rounded down.
+    ///
+    /// It doesn't overflow, even for the values close to the `U256::MAX`,
+    /// as the halves of the values are added, with the carry of their lowest bits recovered.
+    pub fn avg(self, other: Value) -> Value {
+        Value::from_u256(self.to_u256().avg(other.to_u256()))
+    }
+
+    /// Returns the difference of the values, saturating at zero when the `other` is bigger.
+    pub fn saturating_sub(self, other: Value) -> Value {
+        Value::from_u256(self.to_u256().saturating_sub(other.to_u256()))
+    }
+
+    /// Rescales the fixed-point value from the `from_decimals` to the `to_decimals` decimals,
+    /// like the RedStone prices, scaled by 8 decimals, to the 18 decimals of a token.
+    ///
+    /// Reducing the decimals rounds down, increasing them fails with `Error::NumberOverflow`
+    /// when the value doesn't fit the 256 bits afterwards.
+    pub fn rescale(self, from_decimals: u8, to_decimals: u8) -> Result<Value, Error> {
+        let value = self.to_u256();
+
+        let rescaled = if to_decimals >= from_decimals {
+            Value::pow10(to_decimals - from_decimals)
+                .and_then(|factor| value.checked_mul(factor.to_u256()))
+        } else {
+            // the divisor exceeding the 256 bits is bigger than any value
+            Some(
+                Value::pow10(from_decimals - to_decimals)
+                    .map_or(primitive_types::U256::zero(), |divisor| {
+                        value / divisor.to_u256()
+                    }),
+            )
+        };
+
+        rescaled
+            .map(Value::from_u256)
+            .ok_or(Error::NumberOverflow(self))
+    }
+
+    /// Returns the RedStone price, scaled by 8 decimals, as the `u128` fixed-point number of the `decimals` decimals.
+    ///
+    /// Fails with `Error::NumberOverflow` when the rescaled price doesn't fit the `u128`.
+    pub fn to_scaled_u128(self, decimals: u8) -> Result<u128, Error> {
+        let rescaled = self.rescale(REDSTONE_PRICE_DECIMALS, decimals)?;
+        let value = rescaled.to_u256();
+
+        if value > primitive_types::U256::from(u128::MAX) {
+            return Err(Error::NumberOverflow(rescaled));
+        }
+
+        Ok(value.as_u128())
+    }
+
+    /// Interprets the value as a signed number, for the feeds reporting the signed values, like the funding rates.
+    ///
+    /// The values are unsigned by default, the signed convention is opt-in per feed:
+    /// the low 16 bytes hold the number in two's complement and the high 16 bytes must be zeros,
+    /// failing with `Error::NumberOverflow` otherwise. See [`Value::from_i128`].
+    pub fn as_i128(&self) -> Result<i128, Error> {
+        let low = u128::try_from(*self)?;
+
+        Ok(low as i128)
+    }
+
+    /// Converts the signed number to the value of the convention of the [`Value::as_i128`].
+    pub fn from_i128(value: i128) -> Self {
+        Value::from(value as u128)
+    }
+}
+
+/// Serializes the value as its decimal representation, as it doesn't fit the JSON numbers.
+#[cfg(feature = "serde")]
+impl Serialize for Value {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_u256().to_string())
+    }
 }
 
 impl From<Vec<u8>> for Value {
@@ -56,3 +214,206 @@ impl From<Vec<u8>> for Value {
         Self(buff)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use primitive_types::U256;
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    use crate::{network::error::Error, types::VALUE_SIZE, Value};
+
+    const PRICE: u128 = 236389750361; // 2363.89750361 with 8 decimals
+    #[test]
+    fn test_avg() {
+        assert_eq!(Value::from(1u8).avg(4u8.into()), 2u8.into());
+        assert_eq!(Value::from(3u8).avg(3u8.into()), 3u8.into());
+    }
+
+    #[test]
+    fn test_avg_max() {
+        let max = Value::from_u256(U256::MAX);
+
+        assert_eq!(max.avg(max), max);
+        assert_eq!(
+            Value::from(0u8).avg(max),
+            Value::from_u256(U256::MAX / U256::from(2u8))
+        );
+    }
+
+    #[test]
+    fn test_signed_value() {
+        let funding_rate = -1250i128; // -0.0000125 with 8 decimals
+        let value = Value::from_i128(funding_rate);
+
+        assert_eq!(value.as_be_bytes()[..16], [0; 16]);
+        assert_eq!(value.as_be_bytes()[16..], funding_rate.to_be_bytes());
+        assert_eq!(value.as_i128(), Ok(funding_rate));
+        assert_eq!(Value::from(1250u16).as_i128(), Ok(1250));
+
+        for number in [0, i128::MIN, i128::MAX] {
+            assert_eq!(Value::from_i128(number).as_i128(), Ok(number));
+        }
+    }
+
+    #[test]
+    fn test_signed_value_overflow() {
+        let value = Value::from_u256(U256::from(u128::MAX) + 1);
+
+        assert_eq!(value.as_i128(), Err(Error::NumberOverflow(value)));
+    }
+
+    #[test]
+    fn test_saturating_sub() {
+        assert_eq!(Value::from(120u8).saturating_sub(100u8.into()), 20u8.into());
+        assert_eq!(Value::from(100u8).saturating_sub(120u8.into()), 0u8.into());
+        assert_eq!(Value::max().saturating_sub(Value::max()), 0u8.into());
+    }
+
+    #[test]
+    fn test_rescale_up() {
+        assert_eq!(
+            Value::from(PRICE).rescale(8, 18),
+            Ok(Value::from(2363897503610000000000u128))
+        );
+    }
+
+    #[test]
+    fn test_rescale_down() {
+        assert_eq!(
+            Value::from(PRICE).rescale(8, 6),
+            Ok(Value::from(2363897503u128))
+        );
+        assert_eq!(Value::from(PRICE).rescale(8, 0), Ok(Value::from(2363u128)));
+        assert_eq!(Value::from(PRICE).rescale(255, 0), Ok(Value::from(0u8)));
+    }
+
+    #[test]
+    fn test_rescale_same_decimals() {
+        assert_eq!(Value::from(PRICE).rescale(8, 8), Ok(Value::from(PRICE)));
+    }
+
+    #[test]
+    fn test_rescale_overflow() {
+        let max = Value::from_u256(U256::MAX);
+
+        assert_eq!(max.rescale(8, 18), Err(Error::NumberOverflow(max)));
+        assert_eq!(
+            Value::from(1u8).rescale(0, 78),
+            Err(Error::NumberOverflow(Value::from(1u8)))
+        );
+        assert_eq!(
+            Value::from(1u8).rescale(0, 77),
+            Ok(Value::from_u256(U256::exp10(77)))
+        );
+    }
+
+    #[test]
+    fn test_pow10() {
+        assert_eq!(Value::pow10(0), Some(Value::from(1u8)));
+        assert_eq!(Value::pow10(18), Some(Value::from(10u128.pow(18))));
+        assert_eq!(Value::pow10(77), Some(Value::from_u256(U256::exp10(77))));
+        assert_eq!(Value::pow10(78), None);
+        assert_eq!(Value::pow10(u8::MAX), None);
+
+        for n in 0..78 {
+            assert_eq!(
+                Value::pow10(n),
+                Some(Value::from_u256(U256::exp10(n.into())))
+            );
+        }
+    }
+
+    #[test]
+    fn test_to_scaled_u128() {
+        assert_eq!(
+            Value::from(PRICE).to_scaled_u128(18),
+            Ok(2363897503610000000000)
+        );
+        assert_eq!(Value::from(PRICE).to_scaled_u128(6), Ok(2363897503));
+    }
+
+    #[test]
+    fn test_to_scaled_u128_overflow() {
+        // 10^21 with 8 decimals doesn't fit the u128 with 18 decimals, with its max of ~3.4 * 10^38
+        let price = Value::from_u256(U256::exp10(29));
+
+        assert_eq!(
+            price.to_scaled_u128(18),
+            Err(Error::NumberOverflow(Value::from_u256(U256::exp10(39))))
+        );
+        assert_eq!(price.to_scaled_u128(6), Ok(10u128.pow(27)));
+    }
+
+    macro_rules! test_number_round_trip {
+        ($($number_type:ident),*) => {
+            $(
+                for number in [0, 1, $number_type::MAX / 2, $number_type::MAX] {
+                    assert_eq!($number_type::try_from(Value::from(number)), Ok(number));
+                }
+
+                let too_big = Value::from_u256(U256::from($number_type::MAX) + 1);
+                assert_eq!(
+                    $number_type::try_from(too_big),
+                    Err(Error::NumberOverflow(too_big))
+                );
+            )*
+        };
+    }
+
+    #[test]
+    fn test_number_round_trip() {
+        test_number_round_trip!(u8, u16, u32, u64, u128);
+    }
+
+    #[test]
+    fn test_from_number_big_endian() {
+        let mut expected = [0; VALUE_SIZE];
+        expected[VALUE_SIZE - 2..].copy_from_slice(&[0x12, 0x34]);
+
+        assert_eq!(Value::from(0x1234u16), Value(expected));
+        assert_eq!(Value::from(0x1234u128), Value(expected));
+    }
+
+    #[test]
+    fn test_max_has_value_size_bytes() {
+        assert_eq!(Value::max().as_be_bytes().len(), VALUE_SIZE);
+        assert_eq!(Value::max().to_u256(), U256::MAX);
+    }
+
+    #[test]
+    fn test_try_from_be_bytes() {
+        let mut bytes = vec![0; VALUE_SIZE + 1];
+        bytes[VALUE_SIZE - 1..].copy_from_slice(&[0x12, 0x34]);
+
+        assert_eq!(Value::try_from_be_bytes(&bytes), Ok(0x1234u16.into()));
+        assert_eq!(
+            Value::try_from_be_bytes(&bytes[VALUE_SIZE - 1..]),
+            Ok(0x1234u16.into())
+        );
+        assert_eq!(
+            Value::try_from_be_bytes(&[0xff; VALUE_SIZE]),
+            Ok(Value::max())
+        );
+    }
+
+    #[test]
+    fn test_try_from_be_bytes_too_big() {
+        let mut bytes = vec![0; VALUE_SIZE + 1];
+        bytes[0] = 1;
+
+        assert_eq!(
+            Value::try_from_be_bytes(&bytes),
+            Err(Error::SizeNotSupported(VALUE_SIZE + 1))
+        );
+    }
+
+    #[should_panic(expected = "Number to big")]
+    #[test]
+    fn test_from_vec_too_big() {
+        let mut bytes = vec![0; VALUE_SIZE + 1];
+        bytes[0] = 1;
+
+        let _ = Value::from(bytes);
+    }
+}
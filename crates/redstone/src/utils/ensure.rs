@@ -0,0 +1,49 @@
+use crate::network::error::Error;
+
+/// Fails with the `err` unless the `cond` holds.
+#[inline(always)]
+pub fn ensure(cond: bool, err: Error) -> Result<(), Error> {
+    if !cond {
+        return Err(err);
+    }
+
+    Ok(())
+}
+
+pub trait ResultExt<T> {
+    /// Maps the missing value to the `Error` made by the `err`, which is called only then.
+    fn or_error(self, err: impl FnOnce() -> Error) -> Result<T, Error>;
+}
+
+impl<T> ResultExt<T> for Option<T> {
+    #[inline(always)]
+    fn or_error(self, err: impl FnOnce() -> Error) -> Result<T, Error> {
+        self.ok_or_else(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    use crate::{
+        network::error::Error,
+        utils::ensure::{ensure, ResultExt},
+    };
+
+    #[test]
+    fn test_ensure() {
+        assert_eq!(ensure(true, Error::ArrayIsEmpty), Ok(()));
+        assert_eq!(ensure(false, Error::ArrayIsEmpty), Err(Error::ArrayIsEmpty));
+    }
+
+    #[test]
+    fn test_or_error() {
+        assert_eq!(Some(1u8).or_error(|| unreachable!()), Ok(1));
+        assert_eq!(
+            None::<u8>.or_error(|| Error::BufferOverflow(2, 1)),
+            Err(Error::BufferOverflow(2, 1))
+        );
+    }
+}
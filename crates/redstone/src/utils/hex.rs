@@ -0,0 +1,92 @@
+use alloc::{string::String, vec::Vec};
+
+use crate::network::error::Error;
+
+/// Decodes the hex string into bytes, accepting an optional `0x` prefix.
+///
+/// Unlike the `helpers::hex::hex_to_bytes`, doesn't panic on malformed input,
+/// so it's suited for the runtime payload ingestion from strings.
+///
+/// # Arguments
+///
+/// * `hex_str` - A `&str` containing an even number of hex digits, both lower- and uppercase are accepted.
+///
+/// # Returns
+///
+/// Returns a `Result<Vec<u8>, Error>` containing decoded bytes
+/// or `Error::InvalidHex` with the offending part of the input otherwise.
+pub fn try_hex_to_bytes(hex_str: &str) -> Result<Vec<u8>, Error> {
+    let hex_str = hex_str.strip_prefix("0x").unwrap_or(hex_str);
+
+    if hex_str.len() % 2 != 0 {
+        return Err(Error::InvalidHex(hex_str.into()));
+    }
+
+    hex_str
+        .as_bytes()
+        .chunks(2)
+        .map(|pair| match (hex_digit(pair[0]), hex_digit(pair[1])) {
+            (Some(high), Some(low)) => Ok((high << 4) | low),
+            _ => Err(Error::InvalidHex(
+                String::from_utf8_lossy(pair).into_owned(),
+            )),
+        })
+        .collect()
+}
+
+#[inline]
+fn hex_digit(character: u8) -> Option<u8> {
+    match character {
+        b'0'..=b'9' => Some(character - b'0'),
+        b'a'..=b'f' => Some(character - b'a' + 10),
+        b'A'..=b'F' => Some(character - b'A' + 10),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    use crate::{network::error::Error, utils::hex::try_hex_to_bytes};
+
+    #[test]
+    fn test_try_hex_to_bytes() {
+        assert_eq!(try_hex_to_bytes("0a1B2c"), Ok(vec![10, 27, 44]));
+        assert_eq!(try_hex_to_bytes("0x0a1B2c"), Ok(vec![10, 27, 44]));
+        assert_eq!(try_hex_to_bytes("0xff"), Ok(vec![255]));
+    }
+
+    #[test]
+    fn test_try_hex_to_bytes_empty() {
+        assert_eq!(try_hex_to_bytes(""), Ok(vec![]));
+        assert_eq!(try_hex_to_bytes("0x"), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_try_hex_to_bytes_odd_length() {
+        assert_eq!(
+            try_hex_to_bytes("abc"),
+            Err(Error::InvalidHex("abc".into()))
+        );
+        assert_eq!(try_hex_to_bytes("0x0"), Err(Error::InvalidHex("0".into())));
+    }
+
+    #[test]
+    fn test_try_hex_to_bytes_non_hex_character() {
+        assert_eq!(
+            try_hex_to_bytes("0a1g"),
+            Err(Error::InvalidHex("1g".into()))
+        );
+        assert_eq!(
+            try_hex_to_bytes("0X0a"),
+            Err(Error::InvalidHex("0X".into()))
+        );
+        assert_eq!(
+            try_hex_to_bytes(" 0a "),
+            Err(Error::InvalidHex(" 0".into()))
+        );
+        assert_eq!(try_hex_to_bytes("0aé"), Err(Error::InvalidHex("é".into())));
+    }
+}
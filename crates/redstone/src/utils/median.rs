@@ -4,13 +4,29 @@ pub(crate) trait Median {
     type Item;
 
     fn median(self) -> Option<Self::Item>;
+
+    /// Returns the median along with its kind: whether it's one of the values or the average of the two middle ones.
+    fn median_with_kind(self) -> Option<(Self::Item, MedianKind)>;
+}
+
+/// Kind of the median value.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MedianKind {
+    /// The median is one of the values, for the odd number of values.
+    Exact,
+    /// The median is the average of the two middle values, for the even number of values.
+    Interpolated,
 }
 
-trait Avg {
+/// Average of two numbers, rounded down.
+///
+/// Implemented without the intermediate `self + other`, so it doesn't overflow near the maximum values:
+/// both halves are added and the carry of the two lowest bits is recovered separately.
+pub(crate) trait Avg {
     fn avg(self, other: Self) -> Self;
 }
 
-trait Averageable:
+pub(crate) trait Averageable:
     Add<Output = Self> + Shr<Output = Self> + From<u8> + Rem<Output = Self> + Copy
 {
 }
@@ -67,6 +83,15 @@ where
 
         Some(median)
     }
+
+    fn median_with_kind(self) -> Option<(Self::Item, MedianKind)> {
+        let kind = match self.len() % 2 {
+            0 => MedianKind::Interpolated,
+            _ => MedianKind::Exact,
+        };
+
+        self.median().map(|median| (median, kind))
+    }
 }
 
 #[inline]
@@ -91,7 +116,7 @@ mod tests {
     #[cfg(target_arch = "wasm32")]
     use wasm_bindgen_test::wasm_bindgen_test as test;
 
-    use super::{Avg, Median};
+    use super::{Avg, Median, MedianKind};
 
     #[allow(clippy::legacy_numeric_constants)]
     #[test]
@@ -172,6 +197,38 @@ mod tests {
         test_all_permutations(vec![1, 2, 3, 4, 5, 6, 7], 4);
     }
 
+    #[test]
+    fn test_median_with_kind_odd_number_of_elements() {
+        assert_eq!(vec![2].median_with_kind(), Some((2, MedianKind::Exact)));
+        assert_eq!(
+            vec![3, 1, 2].median_with_kind(),
+            Some((2, MedianKind::Exact))
+        );
+        assert_eq!(
+            vec![5, 1, 4, 2, 3].median_with_kind(),
+            Some((3, MedianKind::Exact))
+        );
+    }
+
+    #[test]
+    fn test_median_with_kind_even_number_of_elements() {
+        assert_eq!(
+            vec![1, 3].median_with_kind(),
+            Some((2, MedianKind::Interpolated))
+        );
+        assert_eq!(
+            vec![4000, 1000, 3000, 2000].median_with_kind(),
+            Some((2500, MedianKind::Interpolated))
+        );
+    }
+
+    #[test]
+    fn test_median_with_kind_empty_vector() {
+        let vec: Vec<i32> = vec![];
+
+        assert_eq!(vec.median_with_kind(), None);
+    }
+
     fn test_all_permutations<T: Copy + Ord + Avg + Debug>(numbers: Vec<T>, expected_value: T) {
         let perms: Vec<Vec<_>> = numbers.iter().permutations(numbers.len()).collect();
 
@@ -1,4 +1,6 @@
+pub mod ensure;
 pub mod filter;
+pub mod hex;
 pub mod median;
 pub mod slice;
 pub mod trim;
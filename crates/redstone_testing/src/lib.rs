@@ -149,6 +149,7 @@ macro_rules! test_price_adapter_read_impl {
         use redstone_testing::env::run_env::PriceAdapterRunEnv;
         use redstone_testing::sample::{
             sample_eth_btc_avax_5sig, sample_eth_btc_avax_5sig_2, sample_eth_btc_avax_5sig_old,
+            sample_test_helpers::make_signers, SIGNERS,
         };
 
 
@@ -201,6 +202,18 @@ macro_rules! test_price_adapter_read_impl {
             sample.verify_written_values(&mut price_adapter, None);
         }
 
+        #[should_panic(expected = "Insufficient signer count 3 for #")]
+        #[test]
+        fn [<test_get_prices_under_threshold_ $id>]() {
+            let sample = &sample_eth_btc_avax_5sig();
+            let mut signers = make_signers(&SIGNERS[..3]);
+            signers.extend([vec![0x11; 20], vec![0x22; 20]]);
+            let mut price_adapter: $price_adapter_impl =
+                sample.instantiate_price_adapter_with(signers, 5);
+
+            sample.test_get_prices(&mut price_adapter, None);
+        }
+
         #[should_panic(expected = "Timestamp 1725975870000 is too future for #0")]
         #[test]
         fn [<test_get_prices_timestamp_error_ $id>]() {
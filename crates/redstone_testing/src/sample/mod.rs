@@ -2,7 +2,7 @@ pub mod sample_test_helpers;
 
 use std::collections::HashMap;
 
-use redstone::Value;
+use redstone::{helpers::hex::hex_to_bytes, Value};
 
 #[macro_export]
 macro_rules! hashmap {
@@ -22,6 +22,11 @@ pub const SIGNERS: [&str; 5] = [
     "0x83cba8c619fb629b81a65c2e67fe15cf3e3c9747",
     "0x2c59617248994D12816EE1Fa77CE0a64eEB456BF",
 ];
+/// The well-known development addresses signing the [`sample_large_value`].
+pub const SAMPLE_LARGE_VALUE_SIGNERS: [&str; 2] = [
+    "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266",
+    "0x70997970C51812dc3A010C7d01b50e0d17dc79C8",
+];
 
 #[derive(Debug, Clone)]
 pub struct Sample {
@@ -69,3 +74,17 @@ pub fn sample_eth_btc_avax_5sig_2() -> Sample {
         system_timestamp: SAMPLE_SYSTEM_TIMESTAMP_2,
     }
 }
+
+/// The LARGE feed value of `u128::MAX + 123456790`, exceeding the `u128`.
+///
+/// Signed by the [`SAMPLE_LARGE_VALUE_SIGNERS`], not by the [`SIGNERS`].
+pub fn sample_large_value() -> Sample {
+    Sample {
+        content: include_str!("../../../../sample-data/payload_large_value.hex"),
+        values: hashmap![
+            "LARGE" => hex_to_bytes("01000000000000000000000000075bcd15".into())
+        ],
+        timestamp: 1725975800000,
+        system_timestamp: SAMPLE_SYSTEM_TIMESTAMP,
+    }
+}
@@ -1,6 +1,6 @@
 use redstone::{
     helpers::{hex::make_bytes, iter_into::IterIntoOpt},
-    Value,
+    FeedId, Value,
 };
 
 use crate::{
@@ -8,20 +8,47 @@ use crate::{
     sample::{Sample, SIGNERS},
 };
 
-fn make_feed_ids(feeds: &[&str]) -> Vec<Vec<u8>> {
-    feeds.iter().map(|&s| s.as_bytes().to_vec()).collect()
+fn feed_id_bytes(feeds: &[&str]) -> Vec<Vec<u8>> {
+    feeds
+        .iter()
+        .map(|&feed| {
+            FeedId::from_ticker(feed)
+                .expect("Conversion error")
+                .as_ref()
+                .to_vec()
+        })
+        .collect()
 }
 
 fn signers() -> Vec<Vec<u8>> {
-    make_bytes(SIGNERS.to_vec(), |s| s.to_string())
+    make_signers(&SIGNERS)
+}
+
+/// Makes the raw signer bytes of the hex signer addresses, like the `SIGNERS`.
+pub fn make_signers(signers: &[&str]) -> Vec<Vec<u8>> {
+    make_bytes(signers.to_vec(), |s| s.to_string())
         .into_iter()
-        .map(|x| x.0)
+        .map(|x| x.into_vec())
         .collect()
 }
 
 impl Sample {
     pub fn instantiate_price_adapter<PriceAdapter: PriceAdapterRunEnv>(&self) -> PriceAdapter {
-        PriceAdapter::instantiate(1, signers(), self.system_timestamp.into())
+        self.instantiate_price_adapter_with(signers(), 1)
+    }
+
+    /// Instantiates the price adapter with the given raw signer bytes and the signer count threshold,
+    /// e.g. for testing the configs not matching the sample signers.
+    pub fn instantiate_price_adapter_with<PriceAdapter: PriceAdapterRunEnv>(
+        &self,
+        signers: Vec<Vec<u8>>,
+        signer_count_threshold: u8,
+    ) -> PriceAdapter {
+        PriceAdapter::instantiate(
+            signer_count_threshold,
+            signers,
+            self.system_timestamp.into(),
+        )
     }
 
     pub fn verify_written_values<PriceAdapter: PriceAdapterRunEnv>(
@@ -31,7 +58,7 @@ impl Sample {
     ) {
         let feed_ids = override_feed_ids.unwrap_or(self.feed_ids());
 
-        let values = price_adapter.read_prices(make_feed_ids(&feed_ids));
+        let values = price_adapter.read_prices(feed_id_bytes(&feed_ids));
         let timestamp = price_adapter.read_timestamp(Some(feed_ids.first().unwrap()));
 
         self.verify_results(feed_ids, values.iter_into_opt(), timestamp);
@@ -65,7 +92,7 @@ impl Sample {
         let (timestamp, values) = price_adapter.process_payload(
             run_mode,
             redstone::helpers::hex::hex_to_bytes(self.content.to_string()),
-            make_feed_ids(&feed_ids),
+            feed_id_bytes(&feed_ids),
             self.system_timestamp,
         );
 